@@ -0,0 +1,287 @@
+use crate::alien::ShootingType;
+use crate::black_hole::BlackHole;
+use crate::constants;
+use crate::event::{GameEvent, Subscriber};
+use crate::locale::Locale;
+use crate::session::Session;
+use crate::settings;
+use crate::stats::Stats;
+use crate::ui::{self, Align};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::io::ErrorKind;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum Condition {
+    Score(u64),
+    KillAlien(ShootingType),
+    DestroyAsteroids(u64),
+    Combo(u64),
+    Survive(u64),
+    ClearWaveWithoutThrust,
+    EscapeBlackHole,
+}
+
+impl Condition {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let invalid = || format!("{}{}", constants::strings::INVALID_VALUE_ERROR, text);
+
+        let mut parts = text.split_whitespace();
+        let kind = parts.next().ok_or_else(invalid)?;
+        let arg = parts.next();
+        let count = || arg.and_then(|a| a.parse::<u64>().ok()).ok_or_else(invalid);
+
+        Ok(match kind {
+            "score" => Condition::Score(count()?),
+            "kill_alien" => Condition::KillAlien(match arg {
+                Some("random") => ShootingType::Random,
+                Some("current") => ShootingType::Current,
+                Some("future") => ShootingType::Future,
+                _ => return Err(invalid()),
+            }),
+            "destroy_asteroids" => Condition::DestroyAsteroids(count()?),
+            "combo" => Condition::Combo(count()?),
+            "survive" => Condition::Survive(count()?),
+            "clear_wave_without_thrust" => Condition::ClearWaveWithoutThrust,
+            "escape_black_hole" => Condition::EscapeBlackHole,
+            _ => return Err(invalid()),
+        })
+    }
+}
+
+pub struct Achievement {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub condition: Condition,
+}
+
+pub fn parse_definitions(source: &str) -> Result<Vec<Achievement>, String> {
+    source
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let fields = line.split('|').map(|f| f.trim()).collect::<Vec<&str>>();
+
+            let [id, name, description, condition] = fields[..] else {
+                return Err(format!("{}{}", constants::strings::INVALID_VALUE_ERROR, line));
+            };
+
+            Ok(Achievement {
+                id: id.to_string(),
+                name: name.to_string(),
+                description: description.to_string(),
+                condition: Condition::parse(condition)?,
+            })
+        })
+        .collect()
+}
+
+#[derive(Default)]
+pub struct Achievements {
+    definitions: Vec<Achievement>,
+    unlocked: HashSet<String>,
+    toasts: VecDeque<(usize, f32)>,
+
+    thrusted: bool,
+    clean_wave: bool,
+    pulled: Option<(f32, f32)>,
+    escaped: bool,
+}
+
+impl Achievements {
+    pub fn load() -> Result<Self, String> {
+        let mut achievements = Achievements {
+            definitions: parse_definitions(constants::achievements::DEFINITIONS)?,
+            ..Achievements::default()
+        };
+
+        let Some(path) = settings::get_config_path(constants::achievements::FILE) else {
+            return Ok(achievements);
+        };
+
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(achievements),
+            Err(e) => return Err(e.to_string()),
+        };
+
+        achievements.unlocked = contents
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(String::from)
+            .collect();
+
+        Ok(achievements)
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = settings::get_config_path(constants::achievements::FILE)
+            .ok_or(String::from(constants::strings::CONFIG_DIR_ERROR))?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+
+        let contents = self
+            .definitions
+            .iter()
+            .filter(|a| self.unlocked.contains(&a.id))
+            .map(|a| format!("{}\n", a.id))
+            .collect::<String>();
+
+        fs::write(path, contents).map_err(|e| e.to_string())
+    }
+
+    pub fn get_definitions(&self) -> &[Achievement] {
+        &self.definitions
+    }
+
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked.contains(id)
+    }
+
+    pub fn get_unlocked_count(&self) -> usize {
+        self.definitions.iter().filter(|a| self.is_unlocked(&a.id)).count()
+    }
+
+    pub fn reset_progress(&mut self) {
+        self.thrusted = false;
+        self.clean_wave = false;
+        self.pulled = None;
+        self.escaped = false;
+    }
+
+    pub fn track(&mut self, session: &Session, stats: &Stats) {
+        let Some(player) = session.get_player(0) else {
+            return;
+        };
+
+        self.thrusted |= player.is_thrusting();
+
+        let black_holes = session.get_black_holes();
+        let in_range =
+            |b: &BlackHole| b.get_force(player.get_x(), player.get_y(), 1.0, session.get_tuning()) != (0.0, 0.0);
+
+        if let Some(pos) = self.pulled {
+            let hole = black_holes
+                .iter()
+                .find(|b| (b.get_x(), b.get_y()) == pos)
+                .filter(|b| b.is_alive() && b.is_full_radius());
+
+            match hole {
+                Some(b) if in_range(b) => {}
+                Some(_) => {
+                    self.escaped = true;
+                    self.pulled = None;
+                }
+                None => self.pulled = None,
+            }
+        }
+
+        if self.pulled.is_none() {
+            self.pulled = black_holes
+                .iter()
+                .find(|b| b.is_full_radius() && in_range(b))
+                .map(|b| (b.get_x(), b.get_y()));
+        }
+
+        let met = self
+            .definitions
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| !self.unlocked.contains(&a.id))
+            .filter(|(_, a)| match a.condition {
+                Condition::Score(points) => player.get_score() >= points,
+                Condition::KillAlien(t) => stats.aliens_killed[t.to_byte() as usize] > 0,
+                Condition::DestroyAsteroids(count) => stats.asteroids_destroyed.iter().sum::<u64>() >= count,
+                Condition::Combo(count) => stats.max_combo >= count,
+                Condition::Survive(seconds) => stats.time_survived >= seconds as f32,
+                Condition::ClearWaveWithoutThrust => self.clean_wave,
+                Condition::EscapeBlackHole => self.escaped,
+            })
+            .map(|(i, _)| i)
+            .collect::<Vec<usize>>();
+
+        if met.is_empty() {
+            return;
+        }
+
+        met.into_iter().for_each(|i| {
+            self.unlocked.insert(self.definitions[i].id.clone());
+            self.toasts.push_back((i, constants::achievements::TOAST_DURATION));
+        });
+
+        if let Err(e) = self.save() {
+            println!("{}{}", constants::strings::SAVE_ACHIEVEMENTS_ERROR, e);
+        }
+    }
+
+    pub fn update(&mut self, dt: f32) {
+        if let Some((_, remaining)) = self.toasts.front_mut() {
+            *remaining -= dt;
+
+            if *remaining <= 0.0 {
+                self.toasts.pop_front();
+            }
+        }
+    }
+
+    pub fn render_toasts(
+        &self,
+        canvas: &mut Canvas<Window>,
+        screen_bounds: Rect,
+        scale: f32,
+        locale: &Locale,
+    ) -> Result<(), String> {
+        let Some(&(index, _)) = self.toasts.front() else {
+            return Ok(());
+        };
+
+        let text = format!(
+            "{} {}\n{}",
+            constants::font::STAR_ICON,
+            locale.translate(constants::strings::ACHIEVEMENT_UNLOCKED_TEXT),
+            locale.translate(&self.definitions[index].name)
+        );
+
+        let scale = scale * constants::achievements::TOAST_SCALE;
+        let (width, height) = ui::measure_text(&text, scale);
+        let padding = (constants::font::MARGIN as f32 * scale) as i32;
+
+        let x = screen_bounds.x() + screen_bounds.width() as i32 / 2;
+        let y = screen_bounds.y() + constants::achievements::TOAST_TOP;
+
+        canvas.set_draw_color(Color::RGB(0, 0, 0));
+        let frame = Rect::new(
+            x - width as i32 / 2 - padding,
+            y - padding,
+            width + 2 * padding as u32,
+            height + 2 * padding as u32,
+        );
+        canvas.fill_rect(frame)?;
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.draw_rect(frame)?;
+
+        ui::render_text(&text, x, y, Align::Center, scale, canvas)
+    }
+}
+
+impl Subscriber for Achievements {
+    fn handle_event(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::WaveCleared => {
+                self.clean_wave |= !self.thrusted;
+                self.thrusted = false;
+            }
+            GameEvent::PlayerHit { .. } => self.pulled = None,
+            _ => {}
+        }
+    }
+}
@@ -1,21 +1,43 @@
 use crate::bullet::Bullet;
 use crate::constants;
+use crate::net::bytes::Reader;
 use rand::Rng;
 use rand::seq::IndexedRandom;
 use sdl2::pixels::Color;
 use sdl2::rect::{Point, Rect};
 use sdl2::render::Canvas;
-use sdl2::sys::SDL_GetTicks64;
 use sdl2::video::Window;
 use std::f32::consts::PI;
 
-#[derive(Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub enum ShootingType {
     Random,
     Current,
     Future,
 }
 
+impl ShootingType {
+    pub const ALL: [ShootingType; 3] = [ShootingType::Random, ShootingType::Current, ShootingType::Future];
+
+    pub fn to_byte(&self) -> u8 {
+        match self {
+            ShootingType::Random => 0,
+            ShootingType::Current => 1,
+            ShootingType::Future => 2,
+        }
+    }
+
+    pub fn from_byte(byte: u8) -> Option<ShootingType> {
+        match byte {
+            0 => Some(ShootingType::Random),
+            1 => Some(ShootingType::Current),
+            2 => Some(ShootingType::Future),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct Alien {
     x: f32,
     y: f32,
@@ -30,7 +52,7 @@ pub struct Alien {
 }
 
 impl Alien {
-    pub fn new(score: u64, screen_bounds: Rect) -> Option<Alien> {
+    pub fn new(score: u64, screen_bounds: Rect, now: u64, rng: &mut impl Rng) -> Option<Alien> {
         let mut available_types: Vec<ShootingType> = Vec::new();
 
         if score >= constants::alien::random::MIN_POINTS {
@@ -45,7 +67,7 @@ impl Alien {
             available_types.push(ShootingType::Future);
         }
 
-        let shooting_type = available_types.choose(&mut rand::rng());
+        let shooting_type = available_types.choose(rng);
 
         if shooting_type.is_none() {
             return None;
@@ -53,43 +75,47 @@ impl Alien {
 
         let shooting_type = shooting_type.unwrap();
 
-        let side = rand::rng().random_range(0..4_u8);
+        Some(Self::new_of_type(*shooting_type, screen_bounds, now, rng))
+    }
+
+    pub fn new_of_type(shooting_type: ShootingType, screen_bounds: Rect, now: u64, rng: &mut impl Rng) -> Alien {
+        let side = rng.random_range(0..4_u8);
 
         let (mut x, mut y): (f32, f32) = (0.0, 0.0);
         let (mut vx, mut vy): (f32, f32) = (0.0, 0.0);
 
         match side {
             0 => {
-                x = rand::rng().random_range(0..screen_bounds.width()) as f32;
+                x = rng.random_range(0..screen_bounds.width()) as f32;
                 y = 0.0;
                 vy = 1.0;
             }
             1 => {
                 x = 0.0;
-                y = rand::rng().random_range(0..screen_bounds.height()) as f32;
+                y = rng.random_range(0..screen_bounds.height()) as f32;
                 vx = 1.0;
             }
             2 => {
-                x = rand::rng().random_range(0..screen_bounds.width()) as f32;
+                x = rng.random_range(0..screen_bounds.width()) as f32;
                 y = screen_bounds.height() as f32;
                 vy = -1.0;
             }
             3 => {
                 x = screen_bounds.width() as f32;
-                y = rand::rng().random_range(0..screen_bounds.height()) as f32;
+                y = rng.random_range(0..screen_bounds.height()) as f32;
                 vx = -1.0;
             }
             _ => {}
         }
 
-        Some(match shooting_type {
-            ShootingType::Random => Alien::new_random(x, y, vx, vy),
-            ShootingType::Current => Alien::new_current(x, y, vx, vy),
-            ShootingType::Future => Alien::new_future(x, y, vx, vy),
-        })
+        match shooting_type {
+            ShootingType::Random => Alien::new_random(x, y, vx, vy, now, rng),
+            ShootingType::Current => Alien::new_current(x, y, vx, vy, now, rng),
+            ShootingType::Future => Alien::new_future(x, y, vx, vy, now, rng),
+        }
     }
-    fn new_random(x: f32, y: f32, mut vx: f32, mut vy: f32) -> Self {
-        let vel = rand::rng().random_range(constants::alien::random::VEL_RANGE);
+    fn new_random(x: f32, y: f32, mut vx: f32, mut vy: f32, now: u64, rng: &mut impl Rng) -> Self {
+        let vel = rng.random_range(constants::alien::random::VEL_RANGE);
 
         vx *= vel;
         vy *= vel;
@@ -102,14 +128,14 @@ impl Alien {
             vy,
 
             shooting_type: ShootingType::Random,
-            next_shot: unsafe { SDL_GetTicks64() } + 1000,
+            next_shot: now + 1000,
 
             bullet: None,
         }
     }
 
-    fn new_current(x: f32, y: f32, mut vx: f32, mut vy: f32) -> Self {
-        let vel = rand::rng().random_range(constants::alien::current::VEL_RANGE);
+    fn new_current(x: f32, y: f32, mut vx: f32, mut vy: f32, now: u64, rng: &mut impl Rng) -> Self {
+        let vel = rng.random_range(constants::alien::current::VEL_RANGE);
 
         vx *= vel;
         vy *= vel;
@@ -122,14 +148,14 @@ impl Alien {
             vy,
 
             shooting_type: ShootingType::Current,
-            next_shot: unsafe { SDL_GetTicks64() } + 1000,
+            next_shot: now + 1000,
 
             bullet: None,
         }
     }
 
-    fn new_future(x: f32, y: f32, mut vx: f32, mut vy: f32) -> Self {
-        let vel = rand::rng().random_range(constants::alien::future::VEL_RANGE);
+    fn new_future(x: f32, y: f32, mut vx: f32, mut vy: f32, now: u64, rng: &mut impl Rng) -> Self {
+        let vel = rng.random_range(constants::alien::future::VEL_RANGE);
 
         vx *= vel;
         vy *= vel;
@@ -142,13 +168,21 @@ impl Alien {
             vy,
 
             shooting_type: ShootingType::Future,
-            next_shot: unsafe { SDL_GetTicks64() } + 1000,
+            next_shot: now + 1000,
 
             bullet: None,
         }
     }
 
-    pub fn tick(&mut self, dt: f32, screen_bounds: Rect, score: u64, player: (f32, f32, f32, f32)) {
+    pub fn tick(
+        &mut self,
+        dt: f32,
+        screen_bounds: Rect,
+        score: u64,
+        player: (f32, f32, f32, f32),
+        now: u64,
+        rng: &mut impl Rng,
+    ) {
         self.x += self.vx * dt;
         self.y += self.vy * dt;
 
@@ -168,8 +202,8 @@ impl Alien {
             self.y = 0.0;
         }
 
-        if unsafe { SDL_GetTicks64() } >= self.next_shot {
-            let res = self.shoot(player.0, player.1, player.2, player.3);
+        if now >= self.next_shot {
+            let res = self.shoot(player.0, player.1, player.2, player.3, now, rng);
 
             if res.is_ok() {
                 let shots_per_second = match self.shooting_type {
@@ -178,15 +212,15 @@ impl Alien {
                     ShootingType::Future => constants::alien::future::SHOTS_PER_SECOND_PER_POINT,
                 };
 
-                self.next_shot = unsafe { SDL_GetTicks64() }
+                self.next_shot = now
                     + (1000.0 / (score as f32 * shots_per_second)) as u64;
             }
         }
     }
 
-    fn shoot(&mut self, x: f32, y: f32, vx: f32, vy: f32) -> Result<(), ()> {
+    fn shoot(&mut self, x: f32, y: f32, vx: f32, vy: f32, now: u64, rng: &mut impl Rng) -> Result<(), ()> {
         let angle = match self.shooting_type {
-            ShootingType::Random => rand::rng().random_range(0.0..(2.0 * PI)),
+            ShootingType::Random => rng.random_range(0.0..(2.0 * PI)),
             ShootingType::Current => {
                 let dx = x - self.x;
                 let dy = y - self.y;
@@ -198,47 +232,47 @@ impl Alien {
                 let b_x = self.x + constants::alien::SHOOT_RADIUS * angle_to_target.cos();
                 let b_y = self.y + constants::alien::SHOOT_RADIUS * angle_to_target.sin();
 
-                let dx = x - b_x;
-                let dy = y - b_y;
+                Self::get_intercept(x - b_x, y - b_y, vx, vy).ok_or(())?.0
+            }
+        };
 
-                let r_dot_v = dx * vx + dy * vy;
-                let a_r2 = dx * dx + dy * dy;
-                let a_v2 = vx * vx + vy * vy;
-                let s2 = constants::bullet::VEL * constants::bullet::VEL;
+        let b_x = self.x + constants::alien::SHOOT_RADIUS * angle.cos();
+        let b_y = self.y + constants::alien::SHOOT_RADIUS * angle.sin();
 
-                let discriminant = r_dot_v * r_dot_v - (a_v2 - s2) * a_r2;
-                if discriminant < 0.0 {
-                    return Err(());
-                }
+        self.bullet = Some(Bullet::new(b_x, b_y, angle, None, now));
 
-                let denominator = a_v2 - s2;
-                if denominator.abs() < f32::EPSILON {
-                    return Err(());
-                }
+        Ok(())
+    }
 
-                let t1 = (-r_dot_v + discriminant.sqrt()) / denominator;
-                let t2 = (-r_dot_v - discriminant.sqrt()) / denominator;
+    pub fn get_intercept(dx: f32, dy: f32, vx: f32, vy: f32) -> Option<(f32, f32)> {
+        let r_dot_v = dx * vx + dy * vy;
+        let a_r2 = dx * dx + dy * dy;
+        let a_v2 = vx * vx + vy * vy;
+        let s2 = constants::bullet::VEL * constants::bullet::VEL;
 
-                let t = [t1, t2]
-                    .iter()
-                    .cloned()
-                    .filter(|&t| t > 0.0)
-                    .min_by(|a, b| a.partial_cmp(b).unwrap())
-                    .ok_or(())?;
+        let discriminant = r_dot_v * r_dot_v - (a_v2 - s2) * a_r2;
+        if discriminant < 0.0 {
+            return None;
+        }
 
-                let aim_x = dx + vx * t;
-                let aim_y = dy + vy * t;
+        let denominator = a_v2 - s2;
+        if denominator.abs() < f32::EPSILON {
+            return None;
+        }
 
-                aim_y.atan2(aim_x)
-            }
-        };
+        let t1 = (-r_dot_v + discriminant.sqrt()) / denominator;
+        let t2 = (-r_dot_v - discriminant.sqrt()) / denominator;
 
-        let b_x = self.x + constants::alien::SHOOT_RADIUS * angle.cos();
-        let b_y = self.y + constants::alien::SHOOT_RADIUS * angle.sin();
+        let t = [t1, t2]
+            .iter()
+            .cloned()
+            .filter(|&t| t > 0.0)
+            .min_by(|a, b| a.partial_cmp(b).unwrap())?;
 
-        self.bullet = Some(Bullet::new(b_x, b_y, angle, false));
+        let aim_x = dx + vx * t;
+        let aim_y = dy + vy * t;
 
-        Ok(())
+        Some((aim_y.atan2(aim_x), t))
     }
 
     pub fn render(&self, canvas: &mut Canvas<Window>, screen_bounds: Rect) -> Result<(), String> {
@@ -299,7 +333,11 @@ impl Alien {
     }
 
     pub fn get_type(&self) -> ShootingType {
-        self.shooting_type.clone()
+        self.shooting_type
+    }
+
+    pub fn get_velocity(&self) -> (f32, f32) {
+        (self.vx, self.vy)
     }
 
     pub fn get_x(&self) -> f32 {
@@ -309,4 +347,27 @@ impl Alien {
     pub fn get_y(&self) -> f32 {
         self.y
     }
+
+    pub fn write(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.x.to_le_bytes());
+        buf.extend_from_slice(&self.y.to_le_bytes());
+        buf.extend_from_slice(&self.vx.to_le_bytes());
+        buf.extend_from_slice(&self.vy.to_le_bytes());
+        buf.push(self.shooting_type.to_byte());
+    }
+
+    pub fn read(reader: &mut Reader) -> Option<Alien> {
+        Some(Alien {
+            x: reader.read_f32()?,
+            y: reader.read_f32()?,
+
+            vx: reader.read_f32()?,
+            vy: reader.read_f32()?,
+
+            shooting_type: ShootingType::from_byte(reader.read_u8()?)?,
+            next_shot: 0,
+
+            bullet: None,
+        })
+    }
 }
@@ -1,4 +1,5 @@
 use crate::constants;
+use crate::net::bytes::Reader;
 use rand::Rng;
 use sdl2::pixels::Color;
 use sdl2::rect::{Point, Rect};
@@ -6,6 +7,7 @@ use sdl2::render::Canvas;
 use sdl2::video::Window;
 use std::f32::consts::PI;
 
+#[derive(Clone)]
 pub struct Asteroid {
     x: f32,
     y: f32,
@@ -19,14 +21,15 @@ pub struct Asteroid {
 }
 
 impl Asteroid {
-    pub fn new(x: f32, y: f32, radius: f32) -> Self {
-        let points = (radius * constants::asteroid::POINTS_PER_RADIUS).max(3.0) as i32;
+    pub fn new(x: f32, y: f32, radius: f32, rng: &mut impl Rng) -> Self {
+        let points = (radius * constants::asteroid::POINTS_PER_RADIUS)
+            .clamp(3.0, constants::asteroid::MAX_POINTS) as i32;
 
         let shape = (0..points)
             .map(|i| {
                 let angle = i as f32 * PI * 2.0 / points as f32;
                 let radius_fac = 1.0
-                    + rand::rng().random_range(
+                    + rng.random_range(
                         -constants::asteroid::RADIUS_OFFSET_FAC
                             ..constants::asteroid::RADIUS_OFFSET_FAC,
                     );
@@ -35,8 +38,8 @@ impl Asteroid {
             })
             .collect::<Vec<[f32; 2]>>();
 
-        let vel = rand::rng().random_range(constants::asteroid::VEL_RANGE);
-        let angle = rand::rng().random_range(0.0..PI * 2.0);
+        let vel = rng.random_range(constants::asteroid::VEL_RANGE);
+        let angle = rng.random_range(0.0..PI * 2.0);
 
         Self {
             x,
@@ -93,7 +96,7 @@ impl Asteroid {
         Ok(())
     }
 
-    pub fn get_spawn_location(px: f32, py: f32, screen_bounds: Rect) -> (f32, f32) {
+    pub fn get_spawn_location(px: f32, py: f32, screen_bounds: Rect, rng: &mut impl Rng) -> (f32, f32) {
         let mut counter = 0;
         let mut best_point = (0.0, 0.0);
         let mut max_sd: f32 = 0.0;
@@ -103,8 +106,8 @@ impl Asteroid {
         {
             counter += 1;
 
-            let x = rand::rng().random_range(0..screen_bounds.width()) as f32;
-            let y = rand::rng().random_range(0..screen_bounds.height()) as f32;
+            let x = rng.random_range(0..screen_bounds.width()) as f32;
+            let y = rng.random_range(0..screen_bounds.height()) as f32;
 
             let dx = px - x;
             let dy = py - y;
@@ -119,7 +122,7 @@ impl Asteroid {
         best_point
     }
 
-    pub fn check_split(&self) -> Option<Vec<Asteroid>> {
+    pub fn check_split(&self, rng: &mut impl Rng) -> Option<Vec<Asteroid>> {
         let r = self.radius / 2.0;
 
         if r < constants::asteroid::MIN_RADIUS {
@@ -127,8 +130,8 @@ impl Asteroid {
         }
 
         Some(vec![
-            Asteroid::new(self.x, self.y, r),
-            Asteroid::new(self.x, self.y, r),
+            Asteroid::new(self.x, self.y, r, rng),
+            Asteroid::new(self.x, self.y, r, rng),
         ])
     }
 
@@ -152,7 +155,52 @@ impl Asteroid {
         self.y
     }
     
+    pub fn set_velocity(&mut self, vx: f32, vy: f32) {
+        self.vx = vx;
+        self.vy = vy;
+    }
+
+    pub fn get_velocity(&self) -> (f32, f32) {
+        (self.vx, self.vy)
+    }
+
     pub fn get_radius(&self) -> f32 {
         self.radius
     }
+
+    pub fn write(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.x.to_le_bytes());
+        buf.extend_from_slice(&self.y.to_le_bytes());
+        buf.extend_from_slice(&self.vx.to_le_bytes());
+        buf.extend_from_slice(&self.vy.to_le_bytes());
+        buf.extend_from_slice(&self.radius.to_le_bytes());
+        buf.push(self.shape.len() as u8);
+
+        self.shape.iter().for_each(|p| {
+            buf.extend_from_slice(&p[0].to_le_bytes());
+            buf.extend_from_slice(&p[1].to_le_bytes());
+        });
+    }
+
+    pub fn read(reader: &mut Reader) -> Option<Asteroid> {
+        let x = reader.read_f32()?;
+        let y = reader.read_f32()?;
+        let vx = reader.read_f32()?;
+        let vy = reader.read_f32()?;
+        let radius = reader.read_f32()?;
+        let points = reader.read_u8()?;
+
+        let shape = (0..points)
+            .map(|_| Some([reader.read_f32()?, reader.read_f32()?]))
+            .collect::<Option<Vec<[f32; 2]>>>()?;
+
+        Some(Asteroid {
+            x,
+            y,
+            vx,
+            vy,
+            radius,
+            shape,
+        })
+    }
 }
@@ -0,0 +1,276 @@
+use crate::autopilot::Autopilot;
+use crate::constants;
+use crate::daily::{self, Leaderboard, Replay};
+use crate::input::KeyBindings;
+use crate::locale::Locale;
+use crate::mode::{Leaderboards, Mode};
+use crate::net;
+use crate::session::Session;
+use crate::ui::{self, Align};
+use rand::Rng;
+use sdl2::rect::Rect;
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Page {
+    HighScores,
+    Controls,
+    Demo,
+}
+
+impl Page {
+    pub const ALL: [Page; 3] = [Page::HighScores, Page::Controls, Page::Demo];
+
+    pub fn get_index(self) -> usize {
+        self as usize
+    }
+
+    pub fn get_duration(self) -> f32 {
+        constants::attract::PAGE_SECONDS[self.get_index()]
+    }
+}
+
+pub fn new_demo(screen_bounds: Rect, ship: bool) -> Session {
+    let mut session = Session::new(screen_bounds, ship as usize, rand::rng().random());
+    if !ship {
+        session.get_tuning_mut().max_aliens = 0;
+    }
+    session
+}
+
+pub struct Attract {
+    active: bool,
+    idle: f32,
+    page: Page,
+    timer: f32,
+
+    replay: Option<Replay>,
+    playback: Option<Session>,
+    autopilot: Option<Autopilot>,
+    frame: usize,
+    accumulator: f32,
+
+    scale: f32,
+}
+
+impl Default for Attract {
+    fn default() -> Self {
+        Attract {
+            active: false,
+            idle: 0.0,
+            page: Page::HighScores,
+            timer: 0.0,
+
+            replay: None,
+            playback: None,
+            autopilot: None,
+            frame: 0,
+            accumulator: 0.0,
+
+            scale: 1.0,
+        }
+    }
+}
+
+impl Attract {
+    pub fn new() -> Self {
+        Attract::default()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.active && self.playback.is_some()
+    }
+
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale;
+    }
+
+    pub fn wake(&mut self) -> bool {
+        let active = self.active;
+
+        self.active = false;
+        self.idle = 0.0;
+        self.replay = None;
+        self.playback = None;
+        self.autopilot = None;
+
+        active
+    }
+
+    pub fn update(&mut self, dt: f32, leaderboard: &Leaderboard) {
+        if !self.active {
+            self.idle += dt;
+
+            if self.idle >= constants::attract::IDLE_SECONDS {
+                self.start(leaderboard);
+            }
+            return;
+        }
+
+        self.timer += dt;
+
+        if let Some(session) = &mut self.playback {
+            let tick_dt = daily::get_tick_dt();
+            self.accumulator = (self.accumulator + dt).min(constants::net::MAX_CATCH_UP);
+
+            while self.accumulator >= tick_dt {
+                self.accumulator -= tick_dt;
+
+                let input = match &mut self.autopilot {
+                    Some(autopilot) => Some(autopilot.get_input(session, net::field_bounds())),
+                    None => self.replay.as_ref().and_then(|r| r.get_input(self.frame)),
+                };
+
+                let over = match input {
+                    Some(input) => daily::step(session, input),
+                    None => true,
+                };
+                self.frame += 1;
+
+                if over {
+                    self.timer = self.page.get_duration();
+                    break;
+                }
+            }
+        }
+
+        if self.timer >= self.page.get_duration() {
+            self.next_page();
+        }
+    }
+
+    fn start(&mut self, leaderboard: &Leaderboard) {
+        self.replay = leaderboard
+            .get_top(constants::daily::LEADERBOARD_SIZE)
+            .into_iter()
+            .filter(|r| r.verified != Some(false))
+            .filter_map(|r| daily::get_replay_path(&r.replay))
+            .find_map(|path| Replay::load(&path).ok());
+
+        self.active = true;
+        self.page = Page::HighScores;
+        self.timer = 0.0;
+    }
+
+    fn next_page(&mut self) {
+        self.page = Page::ALL[(self.page.get_index() + 1) % Page::ALL.len()];
+        self.timer = 0.0;
+        self.playback = None;
+        self.autopilot = None;
+
+        if self.page != Page::Demo {
+            return;
+        }
+
+        match &self.replay {
+            Some(replay) => self.playback = Some(daily::new_session(replay.get_seed())),
+            None => {
+                self.playback = Some(daily::new_session(rand::rng().random()));
+                self.autopilot = Some(Autopilot::new(0));
+            }
+        }
+        self.frame = 0;
+        self.accumulator = 0.0;
+    }
+
+    pub fn render(
+        &self,
+        canvas: &mut Canvas<Window>,
+        screen_bounds: Rect,
+        locale: &Locale,
+        leaderboards: &Leaderboards,
+        bindings: &KeyBindings,
+    ) -> Result<(), String> {
+        if !self.active {
+            return Ok(());
+        }
+
+        if let Some(session) = &self.playback {
+            let scale = net::field_scale(screen_bounds);
+            canvas.set_scale(scale, scale)?;
+            session.render(canvas, net::field_bounds())?;
+            canvas.set_scale(1.0, 1.0)?;
+        }
+
+        let center = screen_bounds.x() + screen_bounds.width() as i32 / 2;
+        let mut y = screen_bounds.y() + screen_bounds.height() as i32 / constants::stats::TOP_MARGIN_DIVISOR;
+
+        let title_scale = self.scale * constants::attract::TITLE_SCALE;
+        let title = locale.translate(constants::strings::TITLE_TEXT);
+        ui::render_text(title, center, y, Align::Center, title_scale, canvas)?;
+        y += ui::measure_text(title, title_scale).1 as i32 * 3 / 2;
+
+        let heading = locale.translate(match self.page {
+            Page::HighScores => constants::attract::HIGH_SCORES_TEXT,
+            Page::Controls => constants::attract::CONTROLS_TEXT,
+            Page::Demo => constants::attract::DEMO_TEXT,
+        });
+        ui::render_text(heading, center, y, Align::Center, self.scale, canvas)?;
+        y += ui::measure_text(heading, self.scale).1 as i32 * 2;
+
+        let scale = self.scale * constants::stats::TABLE_SCALE;
+        let padding = (constants::font::MARGIN as f32 * scale) as i32;
+
+        let (labels, values) = match self.page {
+            Page::HighScores => {
+                let scores = leaderboards.get_scores(Mode::Classic);
+                let values = (0..constants::mode::LEADERBOARD_SIZE)
+                    .map(|i| {
+                        scores
+                            .get(i)
+                            .map(|s| s.to_string())
+                            .unwrap_or(String::from(constants::attract::EMPTY_SCORE))
+                    })
+                    .collect::<Vec<String>>();
+
+                (
+                    (1..=constants::mode::LEADERBOARD_SIZE)
+                        .map(|i| format!("{}.", i))
+                        .collect::<Vec<String>>(),
+                    values,
+                )
+            }
+            Page::Controls => (
+                constants::attract::CONTROL_LABELS
+                    .iter()
+                    .map(|l| locale.translate(l).to_string())
+                    .collect(),
+                vec![
+                    bindings.left.name(),
+                    bindings.right.name(),
+                    bindings.thrust.name(),
+                    bindings.fire.name(),
+                    String::from(constants::attract::PAUSE_KEYS),
+                ],
+            ),
+            Page::Demo => (Vec::new(), Vec::new()),
+        };
+
+        if !labels.is_empty() {
+            ui::render_text(&labels.join("\n"), center - padding, y, Align::Right, scale, canvas)?;
+            ui::render_text(&values.join("\n"), center + padding, y, Align::Left, scale, canvas)?;
+        }
+
+        if ((self.timer / constants::attract::BLINK_SECONDS) as u32).is_multiple_of(2) {
+            let scale = self.scale * constants::attract::TEXT_SCALE;
+            let text = locale.translate(constants::attract::PRESS_ANY_KEY_TEXT);
+            ui::render_text(
+                text,
+                center,
+                screen_bounds.y() + screen_bounds.height() as i32
+                    - constants::attract::BOTTOM_MARGIN
+                    - ui::measure_text(text, scale).1 as i32,
+                Align::Center,
+                scale,
+                canvas,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,679 @@
+use crate::alien::ShootingType;
+use crate::constants;
+use crate::event::{GameEvent, Subscriber};
+use crate::session::Session;
+use sdl2::AudioSubsystem;
+use sdl2::audio::{AudioCallback, AudioDevice, AudioSpecDesired};
+use sdl2::rect::Rect;
+use std::collections::HashMap;
+use std::f32::consts::PI;
+use std::fs;
+use std::sync::Arc;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ExplosionSize {
+    Small,
+    Medium,
+    Large,
+}
+
+impl ExplosionSize {
+    pub fn from_radius(radius: f32) -> Self {
+        if radius >= constants::audio::LARGE_EXPLOSION_RADIUS {
+            ExplosionSize::Large
+        } else if radius >= constants::audio::MEDIUM_EXPLOSION_RADIUS {
+            ExplosionSize::Medium
+        } else {
+            ExplosionSize::Small
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Sound {
+    Fire,
+    Thrust,
+    Explosion(ExplosionSize),
+    Siren(ShootingType),
+    Drone,
+    ExtraLife,
+    HeartbeatLow,
+    HeartbeatHigh,
+}
+
+impl Sound {
+    pub const ALL: [Sound; 12] = [
+        Sound::Fire,
+        Sound::Thrust,
+        Sound::Explosion(ExplosionSize::Small),
+        Sound::Explosion(ExplosionSize::Medium),
+        Sound::Explosion(ExplosionSize::Large),
+        Sound::Siren(ShootingType::Random),
+        Sound::Siren(ShootingType::Current),
+        Sound::Siren(ShootingType::Future),
+        Sound::Drone,
+        Sound::ExtraLife,
+        Sound::HeartbeatLow,
+        Sound::HeartbeatHigh,
+    ];
+
+    pub fn is_looping(self) -> bool {
+        matches!(self, Sound::Thrust | Sound::Siren(_) | Sound::Drone)
+    }
+}
+
+struct Noise(u32);
+
+impl Noise {
+    fn next(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0 as f32 / u32::MAX as f32 * 2.0 - 1.0
+    }
+}
+
+pub fn synthesize(sound: Sound, sample_rate: u32) -> Vec<f32> {
+    let rate = sample_rate as f32;
+    let length = |seconds: f32| (seconds * rate) as usize;
+    let mut noise = Noise(constants::audio::NOISE_SEED);
+    let low_pass = |cutoff: f32| 1.0 - (-2.0 * PI * cutoff / rate).exp();
+
+    match sound {
+        Sound::Fire => {
+            let n = length(constants::audio::FIRE_DURATION);
+            let (start, end) = constants::audio::FIRE_FREQUENCIES;
+            let mut phase = 0.0;
+
+            (0..n)
+                .map(|i| {
+                    let t = i as f32 / n as f32;
+                    phase += (start + (end - start) * t) / rate;
+                    (2.0 * PI * phase).sin().signum() * (1.0 - t) * constants::audio::FIRE_GAIN
+                })
+                .collect()
+        }
+        Sound::Thrust => {
+            let a = low_pass(constants::audio::THRUST_CUTOFF);
+            let mut y = 0.0;
+
+            (0..length(constants::audio::THRUST_DURATION))
+                .map(|_| {
+                    y += a * (noise.next() - y);
+                    y * constants::audio::THRUST_GAIN
+                })
+                .collect()
+        }
+        Sound::Explosion(size) => {
+            let size = size as usize;
+            let n = length(constants::audio::EXPLOSION_DURATIONS[size]);
+            let a = low_pass(constants::audio::EXPLOSION_CUTOFFS[size]);
+            let mut y = 0.0;
+
+            (0..n)
+                .map(|i| {
+                    let t = i as f32 / n as f32;
+                    y += a * (noise.next() - y);
+                    y * (1.0 - t).powi(2) * constants::audio::EXPLOSION_GAINS[size]
+                })
+                .collect()
+        }
+        Sound::Siren(shooting_type) => {
+            let index = shooting_type.to_byte() as usize;
+            let (low, high) = constants::audio::SIREN_FREQUENCIES[index];
+            let sweep = constants::audio::SIREN_RATES[index];
+            let mut phase = 0.0;
+
+            (0..length(constants::audio::SIREN_DURATION))
+                .map(|i| {
+                    let t = i as f32 / rate;
+                    let lfo = (2.0 * PI * sweep * t).sin() * 0.5 + 0.5;
+                    phase += (low + (high - low) * lfo) / rate;
+                    (2.0 * PI * phase).sin() * constants::audio::SIREN_GAIN
+                })
+                .collect()
+        }
+        Sound::Drone => (0..length(constants::audio::DRONE_DURATION))
+            .map(|i| {
+                let t = i as f32 / rate;
+                let sum = constants::audio::DRONE_FREQUENCIES
+                    .iter()
+                    .map(|f| (2.0 * PI * f * t).sin())
+                    .sum::<f32>();
+                sum / constants::audio::DRONE_FREQUENCIES.len() as f32 * constants::audio::DRONE_GAIN
+            })
+            .collect(),
+        Sound::ExtraLife => {
+            let n = length(constants::audio::EXTRA_LIFE_NOTE_DURATION);
+
+            constants::audio::EXTRA_LIFE_NOTES
+                .iter()
+                .flat_map(|f| {
+                    (0..n).map(move |i| {
+                        let t = i as f32 / n as f32;
+                        (2.0 * PI * f * i as f32 / rate).sin().signum()
+                            * (1.0 - t)
+                            * constants::audio::EXTRA_LIFE_GAIN
+                    })
+                })
+                .collect()
+        }
+        Sound::HeartbeatLow | Sound::HeartbeatHigh => {
+            let f = constants::audio::HEARTBEAT_FREQUENCIES[(sound == Sound::HeartbeatHigh) as usize];
+            let n = length(constants::audio::HEARTBEAT_DURATION);
+            let a = low_pass(constants::audio::HEARTBEAT_CUTOFF);
+            let mut y = 0.0;
+
+            (0..n)
+                .map(|i| {
+                    let t = i as f32 / n as f32;
+                    y += a * ((2.0 * PI * f * i as f32 / rate).sin().signum() - y);
+                    y * (1.0 - t) * constants::audio::HEARTBEAT_GAIN
+                })
+                .collect()
+        }
+    }
+}
+
+struct Voice {
+    sound: Sound,
+    samples: Arc<Vec<f32>>,
+    position: usize,
+    gain: f32,
+    pan: f32,
+    target_gain: f32,
+    target_pan: f32,
+}
+
+pub struct Mixer {
+    sample_rate: u32,
+    buffers: HashMap<Sound, Arc<Vec<f32>>>,
+    voices: Vec<Voice>,
+    master_volume: f32,
+    sfx_volume: f32,
+}
+
+impl Mixer {
+    pub fn new(sample_rate: u32) -> Self {
+        Mixer {
+            sample_rate,
+            buffers: Sound::ALL
+                .iter()
+                .map(|&s| (s, Arc::new(synthesize(s, sample_rate))))
+                .collect(),
+            voices: Vec::new(),
+            master_volume: 1.0,
+            sfx_volume: 1.0,
+        }
+    }
+
+    pub fn get_sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    pub fn play(&mut self, sound: Sound, gain: f32, pan: f32) {
+        if self.voices.len() >= constants::audio::MAX_VOICES {
+            return;
+        }
+
+        if let Some(samples) = self.buffers.get(&sound) {
+            self.voices.push(Voice {
+                sound,
+                samples: samples.clone(),
+                position: 0,
+                gain,
+                pan,
+                target_gain: gain,
+                target_pan: pan,
+            });
+        }
+    }
+
+    pub fn set_loop(&mut self, sound: Sound, gain: f32, pan: f32) {
+        match self.voices.iter_mut().find(|v| v.sound == sound) {
+            Some(_) if gain <= 0.0 => self.voices.retain(|v| v.sound != sound),
+            Some(voice) => {
+                voice.target_gain = gain;
+                voice.target_pan = pan;
+            }
+            None if gain > 0.0 => self.play(sound, gain, pan),
+            None => {}
+        }
+    }
+
+    pub fn set_volume(&mut self, master_volume: f32, sfx_volume: f32) {
+        self.master_volume = master_volume;
+        self.sfx_volume = sfx_volume;
+    }
+
+    pub fn mix(&mut self, out: &mut [f32]) {
+        out.fill(0.0);
+        let volume = self.master_volume * self.sfx_volume;
+        let smoothing = constants::audio::SMOOTHING;
+
+        self.voices.retain_mut(|voice| {
+            let looping = voice.sound.is_looping();
+
+            for frame in out.chunks_exact_mut(constants::audio::CHANNELS as usize) {
+                if voice.position >= voice.samples.len() {
+                    if !looping {
+                        return false;
+                    }
+                    voice.position = 0;
+                }
+
+                voice.gain += (voice.target_gain - voice.gain) * smoothing;
+                voice.pan += (voice.target_pan - voice.pan) * smoothing;
+
+                let angle = (voice.pan + 1.0) * PI / 4.0;
+                let sample = voice.samples[voice.position] * voice.gain;
+                frame[0] += sample * angle.cos();
+                frame[1] += sample * angle.sin();
+                voice.position += 1;
+            }
+
+            looping || voice.position < voice.samples.len()
+        });
+
+        out.iter_mut()
+            .for_each(|sample| *sample = (*sample * volume).clamp(-1.0, 1.0));
+    }
+}
+
+impl AudioCallback for Mixer {
+    type Channel = f32;
+
+    fn callback(&mut self, out: &mut [f32]) {
+        self.mix(out);
+    }
+}
+
+pub trait Backend {
+    fn play(&mut self, sound: Sound, gain: f32, pan: f32);
+    fn set_loop(&mut self, sound: Sound, gain: f32, pan: f32);
+    fn set_volume(&mut self, master_volume: f32, sfx_volume: f32);
+}
+
+pub struct SdlBackend {
+    device: AudioDevice<Mixer>,
+}
+
+impl SdlBackend {
+    pub fn new(audio_subsystem: &AudioSubsystem) -> Result<Self, String> {
+        let spec = AudioSpecDesired {
+            freq: Some(constants::audio::SAMPLE_RATE as i32),
+            channels: Some(constants::audio::CHANNELS as u8),
+            samples: Some(constants::audio::BUFFER_SAMPLES),
+        };
+
+        let device = audio_subsystem.open_playback(None, &spec, |spec| Mixer::new(spec.freq as u32))?;
+        device.resume();
+
+        Ok(SdlBackend { device })
+    }
+}
+
+impl Backend for SdlBackend {
+    fn play(&mut self, sound: Sound, gain: f32, pan: f32) {
+        self.device.lock().play(sound, gain, pan);
+    }
+
+    fn set_loop(&mut self, sound: Sound, gain: f32, pan: f32) {
+        self.device.lock().set_loop(sound, gain, pan);
+    }
+
+    fn set_volume(&mut self, master_volume: f32, sfx_volume: f32) {
+        self.device.lock().set_volume(master_volume, sfx_volume);
+    }
+}
+
+pub struct NullBackend {
+    mixer: Mixer,
+    played: Vec<Sound>,
+}
+
+impl Default for NullBackend {
+    fn default() -> Self {
+        NullBackend {
+            mixer: Mixer::new(constants::audio::SAMPLE_RATE),
+            played: Vec::new(),
+        }
+    }
+}
+
+impl NullBackend {
+    pub fn new() -> Self {
+        NullBackend::default()
+    }
+
+    pub fn get_played(&self) -> &[Sound] {
+        &self.played
+    }
+
+    pub fn clear(&mut self) {
+        self.played.clear();
+    }
+
+    pub fn render(&mut self, seconds: f32) -> Vec<f32> {
+        let frames = (seconds * self.mixer.get_sample_rate() as f32) as usize;
+        let mut out = vec![0.0; frames * constants::audio::CHANNELS as usize];
+        self.mixer.mix(&mut out);
+        out
+    }
+
+    pub fn write_wav(&mut self, path: &str, seconds: f32) -> Result<(), String> {
+        let samples = self.render(seconds);
+        write_wav(path, &samples, self.mixer.get_sample_rate())
+    }
+}
+
+impl Backend for NullBackend {
+    fn play(&mut self, sound: Sound, gain: f32, pan: f32) {
+        self.played.push(sound);
+        self.mixer.play(sound, gain, pan);
+    }
+
+    fn set_loop(&mut self, sound: Sound, gain: f32, pan: f32) {
+        if gain > 0.0 && !self.played.contains(&sound) {
+            self.played.push(sound);
+        }
+        self.mixer.set_loop(sound, gain, pan);
+    }
+
+    fn set_volume(&mut self, master_volume: f32, sfx_volume: f32) {
+        self.mixer.set_volume(master_volume, sfx_volume);
+    }
+}
+
+pub fn write_wav(path: &str, samples: &[f32], sample_rate: u32) -> Result<(), String> {
+    let channels = constants::audio::CHANNELS;
+    let data_size = samples.len() as u32 * 2;
+    let mut buf: Vec<u8> = Vec::with_capacity(44 + data_size as usize);
+
+    buf.extend_from_slice(b"RIFF");
+    buf.extend_from_slice(&(36 + data_size).to_le_bytes());
+    buf.extend_from_slice(b"WAVE");
+    buf.extend_from_slice(b"fmt ");
+    buf.extend_from_slice(&16u32.to_le_bytes());
+    buf.extend_from_slice(&1u16.to_le_bytes());
+    buf.extend_from_slice(&channels.to_le_bytes());
+    buf.extend_from_slice(&sample_rate.to_le_bytes());
+    buf.extend_from_slice(&(sample_rate * channels as u32 * 2).to_le_bytes());
+    buf.extend_from_slice(&(channels * 2).to_le_bytes());
+    buf.extend_from_slice(&16u16.to_le_bytes());
+    buf.extend_from_slice(b"data");
+    buf.extend_from_slice(&data_size.to_le_bytes());
+
+    samples.iter().for_each(|s| {
+        buf.extend_from_slice(&((s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16).to_le_bytes())
+    });
+
+    fs::write(path, buf).map_err(|e| e.to_string())
+}
+
+pub struct Audio {
+    backend: Box<dyn Backend>,
+    next_heartbeat: f32,
+    heartbeat_high: bool,
+
+    listener: (f32, f32),
+    screen_bounds: Rect,
+}
+
+impl Audio {
+    pub fn new(backend: Box<dyn Backend>) -> Self {
+        Audio {
+            backend,
+            next_heartbeat: 0.0,
+            heartbeat_high: false,
+
+            listener: (0.0, 0.0),
+            screen_bounds: Rect::new(0, 0, 1, 1),
+        }
+    }
+
+    pub fn set_volume(&mut self, master_volume: f32, sfx_volume: f32) {
+        self.backend.set_volume(master_volume, sfx_volume);
+    }
+
+    pub fn play(&mut self, sound: Sound, x: f32, y: f32) {
+        let gain = self.get_attenuation(x, y);
+        let pan = self.get_pan(x);
+        self.backend.play(sound, gain, pan);
+    }
+
+    pub fn update(&mut self, session: &Session, dt: f32, screen_bounds: Rect) {
+        self.screen_bounds = screen_bounds;
+        self.listener = session
+            .get_player(0)
+            .map(|p| (p.get_x(), p.get_y()))
+            .unwrap_or((screen_bounds.width() as f32 / 2.0, screen_bounds.height() as f32 / 2.0));
+
+        match session.get_players().iter().find(|p| p.is_thrusting()) {
+            Some(p) => self.backend.set_loop(Sound::Thrust, 1.0, self.get_pan(p.get_x())),
+            None => self.backend.set_loop(Sound::Thrust, 0.0, 0.0),
+        }
+
+        ShootingType::ALL.into_iter().for_each(|t| {
+            let nearest = session
+                .get_aliens()
+                .iter()
+                .filter(|a| a.get_type() == t)
+                .map(|a| (self.get_attenuation(a.get_x(), a.get_y()), a.get_x()))
+                .max_by(|a, b| a.0.total_cmp(&b.0));
+
+            let (gain, x) = nearest.unwrap_or((0.0, 0.0));
+            self.backend.set_loop(Sound::Siren(t), gain, self.get_pan(x));
+        });
+
+        let loudest = session
+            .get_black_holes()
+            .iter()
+            .map(|b| {
+                let distance = self.get_distance(b.get_x(), b.get_y()).max(1.0);
+                let gain = (b.get_radius() * constants::audio::DRONE_RADIUS_SCALE / distance).min(1.0);
+                (gain, b.get_x())
+            })
+            .max_by(|a, b| a.0.total_cmp(&b.0));
+
+        let (gain, x) = loudest.unwrap_or((0.0, 0.0));
+        self.backend.set_loop(Sound::Drone, gain, self.get_pan(x));
+
+        self.next_heartbeat -= dt;
+        if self.next_heartbeat <= 0.0 {
+            self.backend.play(
+                if self.heartbeat_high {
+                    Sound::HeartbeatHigh
+                } else {
+                    Sound::HeartbeatLow
+                },
+                1.0,
+                0.0,
+            );
+            self.heartbeat_high = !self.heartbeat_high;
+
+            let score = session.get_players().iter().map(|p| p.get_score()).max().unwrap_or(0);
+            self.next_heartbeat = get_heartbeat_interval(score);
+        }
+    }
+
+    pub fn stop(&mut self) {
+        Sound::ALL
+            .into_iter()
+            .filter(|s| s.is_looping())
+            .for_each(|s| self.backend.set_loop(s, 0.0, 0.0));
+    }
+
+    fn get_pan(&self, x: f32) -> f32 {
+        let width = self.screen_bounds.width() as f32;
+        let dx = x - self.listener.0;
+        let dx = dx - width * (dx / width).round();
+
+        (dx / (width / 2.0)).clamp(-1.0, 1.0) * constants::audio::STEREO_WIDTH
+    }
+
+    fn get_distance(&self, x: f32, y: f32) -> f32 {
+        let (width, height) = (self.screen_bounds.width() as f32, self.screen_bounds.height() as f32);
+
+        let dx = (x - self.listener.0).abs() % width;
+        let dy = (y - self.listener.1).abs() % height;
+
+        dx.min(width - dx).hypot(dy.min(height - dy))
+    }
+
+    fn get_attenuation(&self, x: f32, y: f32) -> f32 {
+        let (width, height) = (self.screen_bounds.width() as f32, self.screen_bounds.height() as f32);
+        let furthest = (width / 2.0).hypot(height / 2.0);
+
+        1.0 - (1.0 - constants::audio::MIN_GAIN) * (self.get_distance(x, y) / furthest).min(1.0)
+    }
+}
+impl Subscriber for Audio {
+    fn handle_event(&mut self, event: &GameEvent) {
+        let (sound, (x, y)) = match *event {
+            GameEvent::BulletFired { owner: Some(_), pos } => (Sound::Fire, pos),
+            GameEvent::AsteroidDestroyed { radius, pos, .. } => {
+                (Sound::Explosion(ExplosionSize::from_radius(radius)), pos)
+            }
+            GameEvent::AlienKilled { pos, .. } => (Sound::Explosion(ExplosionSize::Medium), pos),
+            GameEvent::PlayerHit { pos, .. } => (Sound::Explosion(ExplosionSize::Large), pos),
+            GameEvent::ExtraLife { .. } => (Sound::ExtraLife, self.listener),
+            _ => return,
+        };
+
+        self.play(sound, x, y);
+    }
+}
+
+fn get_heartbeat_interval(score: u64) -> f32 {
+    let waves = constants::asteroid::SPAWN_DELAYS;
+    let wave = waves.iter().filter(|d| score >= d[0]).count().saturating_sub(1);
+    let progress = wave as f32 / (waves.len() - 1).max(1) as f32;
+
+    let (slowest, fastest) = constants::audio::HEARTBEAT_INTERVALS;
+    slowest + (fastest - slowest) * progress
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::HitCause;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    impl Backend for Rc<RefCell<NullBackend>> {
+        fn play(&mut self, sound: Sound, gain: f32, pan: f32) {
+            self.borrow_mut().play(sound, gain, pan);
+        }
+
+        fn set_loop(&mut self, sound: Sound, gain: f32, pan: f32) {
+            self.borrow_mut().set_loop(sound, gain, pan);
+        }
+
+        fn set_volume(&mut self, master_volume: f32, sfx_volume: f32) {
+            self.borrow_mut().set_volume(master_volume, sfx_volume);
+        }
+    }
+
+    #[test]
+    fn events_play_sounds() {
+        let backend = Rc::new(RefCell::new(NullBackend::new()));
+        let mut audio = Audio::new(Box::new(backend.clone()));
+
+        [
+            GameEvent::BulletFired { owner: Some(0), pos: (0.0, 0.0) },
+            GameEvent::BulletFired { owner: None, pos: (0.0, 0.0) },
+            GameEvent::AsteroidDestroyed {
+                radius: constants::audio::LARGE_EXPLOSION_RADIUS,
+                pos: (0.0, 0.0),
+                by: Some(0),
+            },
+            GameEvent::AsteroidDestroyed {
+                radius: constants::audio::MEDIUM_EXPLOSION_RADIUS,
+                pos: (0.0, 0.0),
+                by: Some(0),
+            },
+            GameEvent::AsteroidDestroyed {
+                radius: constants::audio::MEDIUM_EXPLOSION_RADIUS - 1.0,
+                pos: (0.0, 0.0),
+                by: None,
+            },
+            GameEvent::AlienKilled {
+                shooting_type: ShootingType::Random,
+                pos: (0.0, 0.0),
+                by: Some(0),
+            },
+            GameEvent::PlayerHit {
+                player: 0,
+                cause: HitCause::Asteroid,
+                assisted: false,
+                pos: (0.0, 0.0),
+            },
+            GameEvent::ExtraLife { player: 0 },
+            GameEvent::WaveCleared,
+        ]
+        .iter()
+        .for_each(|e| audio.handle_event(e));
+
+        assert_eq!(
+            backend.borrow().get_played(),
+            [
+                Sound::Fire,
+                Sound::Explosion(ExplosionSize::Large),
+                Sound::Explosion(ExplosionSize::Medium),
+                Sound::Explosion(ExplosionSize::Small),
+                Sound::Explosion(ExplosionSize::Medium),
+                Sound::Explosion(ExplosionSize::Large),
+                Sound::ExtraLife,
+            ]
+        );
+    }
+
+    #[test]
+    fn heartbeat_speeds_up_with_score() {
+        let intervals = constants::asteroid::SPAWN_DELAYS
+            .iter()
+            .map(|d| get_heartbeat_interval(d[0]))
+            .collect::<Vec<f32>>();
+
+        assert!(intervals.windows(2).all(|w| w[1] < w[0]));
+        assert_eq!(intervals[0], constants::audio::HEARTBEAT_INTERVALS.0);
+        assert_eq!(intervals[intervals.len() - 1], constants::audio::HEARTBEAT_INTERVALS.1);
+    }
+
+    #[test]
+    fn write_wav_round_trip() {
+        let mut backend = NullBackend::new();
+        Sound::ALL.into_iter().for_each(|s| backend.play(s, 1.0, 0.0));
+
+        let samples = backend.render(0.5);
+        assert!(samples.iter().all(|s| (-1.0..=1.0).contains(s)));
+
+        let path = std::env::temp_dir().join("asteroids_write_wav_round_trip.wav");
+        let path = path.to_str().unwrap();
+        write_wav(path, &samples, constants::audio::SAMPLE_RATE).unwrap();
+        let buf = fs::read(path).unwrap();
+        fs::remove_file(path).unwrap();
+
+        let u16_at = |i: usize| u16::from_le_bytes([buf[i], buf[i + 1]]);
+        let u32_at = |i: usize| u32::from_le_bytes([buf[i], buf[i + 1], buf[i + 2], buf[i + 3]]);
+
+        assert_eq!(&buf[0..4], b"RIFF");
+        assert_eq!(u32_at(4) as usize, buf.len() - 8);
+        assert_eq!(&buf[8..16], b"WAVEfmt ");
+        assert_eq!(u16_at(22), constants::audio::CHANNELS);
+        assert_eq!(u32_at(24), constants::audio::SAMPLE_RATE);
+        assert_eq!(&buf[36..40], b"data");
+        assert_eq!(u32_at(40) as usize, samples.len() * 2);
+        assert_eq!(buf.len(), 44 + samples.len() * 2);
+
+        let frames = samples.len() / constants::audio::CHANNELS as usize;
+        assert_eq!(frames, (0.5 * constants::audio::SAMPLE_RATE as f32) as usize);
+
+        buf[44..].chunks_exact(2).zip(&samples).for_each(|(bytes, s)| {
+            let decoded = i16::from_le_bytes([bytes[0], bytes[1]]) as f32 / i16::MAX as f32;
+            assert!((-1.0..=1.0).contains(&decoded));
+            assert!((decoded - s).abs() < 1e-3);
+        });
+    }
+}
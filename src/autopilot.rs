@@ -0,0 +1,216 @@
+use crate::alien::{Alien, ShootingType};
+use crate::constants;
+use crate::input::Input;
+use crate::player::Player;
+use crate::session::Session;
+use sdl2::rect::Rect;
+use std::f32::consts::PI;
+
+struct Threat {
+    time: f32,
+    miss_x: f32,
+    miss_y: f32,
+}
+
+pub struct Autopilot {
+    player: usize,
+    fire_held: bool,
+}
+
+impl Autopilot {
+    pub fn new(player: usize) -> Self {
+        Autopilot {
+            player,
+            fire_held: false,
+        }
+    }
+
+    pub fn get_input(&mut self, session: &Session, screen_bounds: Rect) -> Input {
+        let Some(player) = session.get_player(self.player) else {
+            return Input::default();
+        };
+
+        if let Some(heading) = Self::get_escape_heading(player, session, screen_bounds) {
+            self.fire_held = false;
+            return Self::steer(player, heading, true);
+        }
+
+        let Some((angle, distance, tolerance)) = Self::get_target(player, session, screen_bounds) else {
+            self.fire_held = false;
+            return Self::steer(player, player.get_angle(), false);
+        };
+
+        let (_, _, vx, vy) = player.get_pos_and_vel();
+        let speed = (vx * vx + vy * vy).sqrt();
+        let approach = distance > constants::autopilot::ENGAGE_DISTANCE
+            && speed < constants::autopilot::CRUISE_SPEED;
+
+        let mut input = Self::steer(player, angle, approach);
+
+        if Self::angle_diff(player.get_angle(), angle).abs() < tolerance {
+            input.fire = !self.fire_held;
+        }
+        self.fire_held = input.fire;
+
+        input
+    }
+
+    fn get_escape_heading(player: &Player, session: &Session, screen_bounds: Rect) -> Option<f32> {
+        let (px, py, pvx, pvy) = player.get_pos_and_vel();
+
+        if let Some(b) = session.get_black_holes().iter().find(|b| {
+            let dx = Self::wrap(b.get_x() - px, screen_bounds.width());
+            let dy = Self::wrap(b.get_y() - py, screen_bounds.height());
+            dx * dx + dy * dy
+                < (session.get_tuning().black_hole_range * b.get_radius()).powi(2)
+        }) {
+            let dx = Self::wrap(b.get_x() - px, screen_bounds.width());
+            let dy = Self::wrap(b.get_y() - py, screen_bounds.height());
+            return Some((-dy).atan2(-dx));
+        }
+
+        let mut threats = Vec::new();
+        let mut check = |x: f32, y: f32, vx: f32, vy: f32, radius: f32| {
+            let rx = Self::wrap(x - px, screen_bounds.width());
+            let ry = Self::wrap(y - py, screen_bounds.height());
+            let rvx = vx - pvx;
+            let rvy = vy - pvy;
+
+            let v2 = rvx * rvx + rvy * rvy;
+            let time = if v2 > f32::EPSILON {
+                (-(rx * rvx + ry * rvy) / v2).max(0.0)
+            } else {
+                0.0
+            };
+
+            let miss_x = rx + rvx * time;
+            let miss_y = ry + rvy * time;
+            let clearance = radius + constants::player::RADIUS;
+
+            if time < constants::autopilot::EVADE_TIME
+                && miss_x * miss_x + miss_y * miss_y < clearance * clearance
+            {
+                threats.push(Threat {
+                    time,
+                    miss_x,
+                    miss_y,
+                });
+            }
+        };
+
+        session.get_asteroids().iter().for_each(|a| {
+            let (vx, vy) = a.get_velocity();
+            check(
+                a.get_x(),
+                a.get_y(),
+                vx,
+                vy,
+                a.get_radius() * (1.0 + constants::asteroid::RADIUS_OFFSET_FAC),
+            );
+        });
+
+        session.get_aliens().iter().for_each(|a| {
+            let (vx, vy) = a.get_velocity();
+            check(a.get_x(), a.get_y(), vx, vy, constants::alien::RADIUS);
+        });
+
+        session
+            .get_bullets()
+            .iter()
+            .filter(|b| b.get_owner().is_none())
+            .for_each(|b| {
+                let (x, y) = b.get_location();
+                let (vx, vy) = b.get_velocity();
+                check(x, y, vx, vy, 0.0);
+            });
+
+        let threat = threats
+            .iter()
+            .min_by(|a, b| a.time.total_cmp(&b.time))?;
+
+        if threat.miss_x.abs() + threat.miss_y.abs() < f32::EPSILON {
+            return Some(player.get_angle() + PI / 2.0);
+        }
+
+        Some((-threat.miss_y).atan2(-threat.miss_x))
+    }
+
+    fn get_target(player: &Player, session: &Session, screen_bounds: Rect) -> Option<(f32, f32, f32)> {
+        let (px, py, _, _) = player.get_pos_and_vel();
+        let range = constants::bullet::VEL * constants::bullet::LIFESPAN as f32 / 1000.0;
+
+        let mut targets = Vec::new();
+
+        session.get_asteroids().iter().for_each(|a| {
+            let (vx, vy) = a.get_velocity();
+            targets.push((a.get_x(), a.get_y(), vx, vy, a.get_radius(), 1.0));
+        });
+
+        session.get_aliens().iter().for_each(|a| {
+            let (vx, vy) = a.get_velocity();
+            let priority = match a.get_type() {
+                ShootingType::Random => constants::autopilot::RANDOM_PRIORITY,
+                ShootingType::Current => constants::autopilot::CURRENT_PRIORITY,
+                ShootingType::Future => constants::autopilot::FUTURE_PRIORITY,
+            };
+            targets.push((
+                a.get_x(),
+                a.get_y(),
+                vx,
+                vy,
+                constants::alien::RADIUS,
+                priority,
+            ));
+        });
+
+        targets
+            .iter()
+            .map(|&(x, y, vx, vy, radius, priority)| {
+                let dx = Self::wrap(x - px, screen_bounds.width());
+                let dy = Self::wrap(y - py, screen_bounds.height());
+                let distance = (dx * dx + dy * dy).sqrt();
+
+                let angle = match Alien::get_intercept(dx, dy, vx, vy) {
+                    Some((angle, t)) if t * constants::bullet::VEL < range => angle,
+                    _ => dy.atan2(dx),
+                };
+
+                let turn = Self::angle_diff(player.get_angle(), angle).abs();
+                let cost = (distance + turn * constants::autopilot::TURN_COST) / priority;
+                let tolerance = radius
+                    .atan2(distance)
+                    .max(constants::autopilot::MIN_FIRE_TOLERANCE);
+
+                (cost, angle, distance, tolerance)
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, angle, distance, tolerance)| (angle, distance, tolerance))
+    }
+
+    fn steer(player: &Player, heading: f32, thrust: bool) -> Input {
+        let diff = Self::angle_diff(player.get_angle(), heading);
+
+        Input {
+            left: diff < -constants::autopilot::TURN_TOLERANCE,
+            right: diff > constants::autopilot::TURN_TOLERANCE,
+            thrust: thrust && diff.abs() < constants::autopilot::THRUST_TOLERANCE,
+            fire: false,
+        }
+    }
+
+    fn angle_diff(from: f32, to: f32) -> f32 {
+        (to - from + PI).rem_euclid(2.0 * PI) - PI
+    }
+
+    fn wrap(d: f32, size: u32) -> f32 {
+        let size = size as f32;
+
+        if d > size / 2.0 {
+            d - size
+        } else if d < -size / 2.0 {
+            d + size
+        } else {
+            d
+        }
+    }
+}
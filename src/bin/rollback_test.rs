@@ -0,0 +1,85 @@
+use asteroids::constants;
+use asteroids::input::Input;
+use asteroids::net::field_bounds;
+use asteroids::net::rollback::RollbackPeer;
+use asteroids::net::transport::{LocalTransport, LossyTransport};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::process::ExitCode;
+
+fn arg<T: std::str::FromStr>(args: &[String], name: &str, default: T) -> T {
+    args.iter()
+        .position(|a| a == name)
+        .and_then(|i| args.get(i + 1))
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(default)
+}
+
+fn random_input(rng: &mut StdRng, last: Input) -> Input {
+    if rng.random_range(0..10) != 0 {
+        return last;
+    }
+
+    Input::from_byte(rng.random_range(0..16))
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+
+    let frames = arg(&args, "--frames", constants::rollback::TEST_FRAMES);
+    let seed = arg(&args, "--seed", constants::rollback::DEFAULT_SEED);
+    let loss = arg(&args, "--loss", 0.1_f32);
+    let latency = arg(&args, "--latency", 60_u64);
+    let jitter = arg(&args, "--jitter", 20_u64);
+
+    let (a, b) = LocalTransport::pair();
+    let a = LossyTransport::new(Box::new(a), loss, latency, jitter, seed);
+    let b = LossyTransport::new(Box::new(b), loss, latency, jitter, seed + 1);
+
+    let mut peers = [
+        RollbackPeer::new(Box::new(a), 0, seed, field_bounds()),
+        RollbackPeer::new(Box::new(b), 1, seed, field_bounds()),
+    ];
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut inputs = [Input::default(); 2];
+
+    for _ in 0..frames {
+        inputs = inputs.map(|last| random_input(&mut rng, last));
+        peers
+            .iter_mut()
+            .zip(inputs)
+            .for_each(|(peer, input)| peer.step(input));
+    }
+
+    for _ in 0..constants::rollback::TEST_SETTLE_FRAMES {
+        peers
+            .iter_mut()
+            .zip(inputs)
+            .for_each(|(peer, input)| peer.step(input));
+    }
+
+    peers.iter().enumerate().for_each(|(i, peer)| {
+        println!(
+            "{} {}: frame {}, rollbacks {}",
+            constants::strings::PLAYER_TEXT,
+            i + 1,
+            peer.get_frame(),
+            peer.get_rollbacks()
+        );
+    });
+
+    if let Some(frame) = peers.iter().filter_map(|p| p.get_desync()).min() {
+        println!("{}{}", constants::strings::DESYNC_TEXT, frame);
+        return ExitCode::FAILURE;
+    }
+
+    let verified = peers
+        .iter()
+        .filter_map(|p| p.get_last_verified_frame())
+        .min()
+        .unwrap_or(0);
+    println!("{}{}", constants::strings::NO_DESYNC_TEXT, verified);
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,19 @@
+use asteroids::constants;
+use asteroids::net::server::Server;
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+
+    let port = args
+        .iter()
+        .position(|a| a == "--port")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(constants::net::DEFAULT_PORT);
+    let versus = args.iter().any(|a| a == "--versus");
+
+    let mut server = Server::new(port, versus).unwrap_or_else(|e| panic!("{}", e));
+
+    println!("{}{}", constants::strings::SERVER_LISTENING, port);
+    server.run();
+}
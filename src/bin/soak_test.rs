@@ -0,0 +1,70 @@
+use asteroids::autopilot::Autopilot;
+use asteroids::constants;
+use asteroids::net::field_bounds;
+use asteroids::session::Session;
+use std::process::ExitCode;
+
+fn arg<T: std::str::FromStr>(args: &[String], name: &str, default: T) -> T {
+    args.iter()
+        .position(|a| a == name)
+        .and_then(|i| args.get(i + 1))
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(default)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+
+    let frames = arg(&args, "--frames", constants::autopilot::SOAK_FRAMES);
+    let sessions = arg(&args, "--sessions", constants::autopilot::SOAK_SESSIONS);
+    let seed = arg(&args, "--seed", constants::autopilot::DEFAULT_SEED);
+
+    let bounds = field_bounds();
+    let dt = constants::net::TICK_MS as f32 / 1000.0;
+    let mut failed = false;
+
+    for i in 0..sessions {
+        let mut session = Session::new(bounds, 1, seed + i);
+        let mut autopilot = Autopilot::new(0);
+
+        let mut deaths = 0;
+        let mut best = 0;
+
+        for frame in 0..frames {
+            let input = autopilot.get_input(&session, bounds);
+            session.set_input(0, input);
+
+            session.tick(dt, bounds).iter().for_each(|&p| {
+                deaths += 1;
+                session.die(bounds);
+                session.respawn(p, bounds);
+            });
+
+            let Some(player) = session.get_player(0) else {
+                break;
+            };
+            best = best.max(player.get_score());
+
+            let (x, y, vx, vy) = player.get_pos_and_vel();
+            if ![x, y, vx, vy].iter().all(|v| v.is_finite()) {
+                println!("{}{}", constants::strings::SOAK_INVALID_STATE_ERROR, frame);
+                failed = true;
+                break;
+            }
+        }
+
+        println!(
+            "seed {}: best score {}, deaths {}, state hash {:016x}",
+            seed + i,
+            best,
+            deaths,
+            session.hash()
+        );
+    }
+
+    if failed {
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,39 @@
+use asteroids::constants;
+use asteroids::daily::Replay;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+
+    let Some(path) = args.get(1).map(PathBuf::from) else {
+        println!("{}", constants::strings::MISSING_REPLAY_ERROR);
+        return ExitCode::FAILURE;
+    };
+
+    let replay = match Replay::load(&path) {
+        Ok(replay) => replay,
+        Err(e) => {
+            println!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!(
+        "date {}: seed {:016x}, score {}, frames {}, state hash {:016x}",
+        replay.get_date(),
+        replay.get_seed(),
+        replay.get_score(),
+        replay.get_frames(),
+        replay.get_hash()
+    );
+
+    if let Err(e) = replay.verify() {
+        println!("{}", e);
+        return ExitCode::FAILURE;
+    }
+
+    println!("{}", constants::strings::REPLAY_VERIFIED_TEXT);
+
+    ExitCode::SUCCESS
+}
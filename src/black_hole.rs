@@ -1,12 +1,14 @@
 use crate::constants;
+use crate::net::bytes::Reader;
+use crate::tuning::Tuning;
 use rand::Rng;
 use sdl2::pixels::Color;
 use sdl2::rect::{Point, Rect};
 use sdl2::render::Canvas;
-use sdl2::sys::SDL_GetTicks64;
 use sdl2::video::Window;
 use std::f32::consts::PI;
 
+#[derive(Clone)]
 pub struct BlackHole {
     x: f32,
     y: f32,
@@ -20,14 +22,14 @@ pub struct BlackHole {
 }
 
 impl BlackHole {
-    pub fn new(screen_bounds: Rect) -> Self {
-        let x = rand::rng().random_range(0..screen_bounds.width());
-        let y = rand::rng().random_range(0..screen_bounds.height());
+    pub fn new(screen_bounds: Rect, now: u64, rng: &mut impl Rng) -> Self {
+        let x = rng.random_range(0..screen_bounds.width());
+        let y = rng.random_range(0..screen_bounds.height());
 
-        let max_radius = rand::rng().random_range(constants::black_hole::MAX_RADII_RANGE);
-        let max_time = rand::rng().random_range(constants::black_hole::MAX_TIME_RANGE);
+        let max_radius = rng.random_range(constants::black_hole::MAX_RADII_RANGE);
+        let max_time = rng.random_range(constants::black_hole::MAX_TIME_RANGE);
 
-        let shrink_time = unsafe { SDL_GetTicks64() }
+        let shrink_time = now
             + (1000.0 * max_radius / constants::black_hole::GROWTH_RATE) as u64
             + max_time;
 
@@ -46,10 +48,10 @@ impl BlackHole {
         }
     }
 
-    pub fn tick(&mut self, dt: f32) {
+    pub fn tick(&mut self, dt: f32, now: u64) {
         if self.expanding {
             self.radius += constants::black_hole::GROWTH_RATE * dt;
-        } else if unsafe { SDL_GetTicks64() } >= self.shrink_time {
+        } else if now >= self.shrink_time {
             self.radius -= constants::black_hole::SHRINK_RATE * dt;
         }
 
@@ -104,15 +106,15 @@ impl BlackHole {
         Ok(())
     }
 
-    pub fn get_force(&self, x: f32, y: f32, dt: f32) -> (f32, f32){
+    pub fn get_force(&self, x: f32, y: f32, dt: f32, tuning: &Tuning) -> (f32, f32){
         let dx = self.x - x;
         let dy = self.y - y;
         let d = (dx * dx + dy * dy).sqrt();
 
-        let range = constants::black_hole::RANGE_FAC * self.radius;
+        let range = tuning.black_hole_range * self.radius;
 
         if d <= range {
-            let pull = self.radius * constants::black_hole::FORCE_FAC / (d * d);
+            let pull = self.radius * tuning.black_hole_force / (d * d);
             let fac = dt * pull / d;
 
             return (fac * dx, fac * dy);
@@ -121,7 +123,51 @@ impl BlackHole {
         (0.0, 0.0)
     }
 
+    pub fn set_location(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+    }
+
+    pub fn get_x(&self) -> f32 {
+        self.x
+    }
+
+    pub fn get_y(&self) -> f32 {
+        self.y
+    }
+
+    pub fn get_radius(&self) -> f32 {
+        self.radius
+    }
+
+    pub fn is_full_radius(&self) -> bool {
+        self.radius >= self.max_radius
+    }
+
     pub fn is_alive(&self) -> bool {
         self.radius >= 0.0
     }
+
+    pub fn write(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.x.to_le_bytes());
+        buf.extend_from_slice(&self.y.to_le_bytes());
+        buf.extend_from_slice(&self.angle.to_le_bytes());
+        buf.extend_from_slice(&self.max_radius.to_le_bytes());
+        buf.extend_from_slice(&self.radius.to_le_bytes());
+    }
+
+    pub fn read(reader: &mut Reader) -> Option<BlackHole> {
+        Some(BlackHole {
+            x: reader.read_f32()?,
+            y: reader.read_f32()?,
+
+            angle: reader.read_f32()?,
+
+            max_radius: reader.read_f32()?,
+            radius: reader.read_f32()?,
+
+            expanding: true,
+            shrink_time: 0,
+        })
+    }
 }
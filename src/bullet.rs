@@ -1,10 +1,10 @@
 use crate::constants;
+use crate::net::bytes::Reader;
 use crate::particle::Particle;
 use rand::Rng;
 use sdl2::pixels::Color;
 use sdl2::rect::Rect;
 use sdl2::render::Canvas;
-use sdl2::sys::SDL_GetTicks64;
 use sdl2::video::Window;
 
 #[derive(Clone)]
@@ -19,11 +19,11 @@ pub struct Bullet {
     particles_to_spawn: Vec<Particle>,
 
     pub to_die: bool,
-    is_player_shot: bool,
+    owner: Option<usize>,
 }
 
 impl Bullet {
-    pub fn new(x: f32, y: f32, angle: f32, is_player_shot: bool) -> Bullet {
+    pub fn new(x: f32, y: f32, angle: f32, owner: Option<usize>, now: u64) -> Bullet {
         let vx = constants::bullet::VEL * angle.cos();
         let vy = constants::bullet::VEL * angle.sin();
 
@@ -32,17 +32,17 @@ impl Bullet {
             y,
             vx,
             vy,
-            death: unsafe { SDL_GetTicks64() } + constants::bullet::LIFESPAN,
+            death: now + constants::bullet::LIFESPAN,
 
-            last_particle: unsafe { SDL_GetTicks64() },
+            last_particle: now,
             particles_to_spawn: Vec::new(),
 
             to_die: false,
-            is_player_shot,
+            owner,
         }
     }
 
-    pub fn tick(&mut self, dt: f32, screen_bounds: Rect) {
+    pub fn tick(&mut self, dt: f32, screen_bounds: Rect, now: u64, rng: &mut impl Rng) {
         self.x += self.vx * dt;
         self.y += self.vy * dt;
 
@@ -52,9 +52,9 @@ impl Bullet {
             let num_particles = (constants::particle::bullet::PARTICLES_PER_SECOND / fps) as i32;
 
             (0..num_particles).for_each(|_| {
-                let vel = -rand::rng().random_range(constants::particle::bullet::VEL_RANGE);
+                let vel = -rng.random_range(constants::particle::bullet::VEL_RANGE);
                 let angle = self.vy.atan2(self.vx)
-                    + rand::rng().random_range(
+                    + rng.random_range(
                         -constants::particle::bullet::ANGLE_OFFSET
                             ..constants::particle::bullet::ANGLE_OFFSET,
                     );
@@ -63,19 +63,19 @@ impl Bullet {
                 let vy = vel * angle.sin();
 
                 self.particles_to_spawn
-                    .push(Particle::new(self.x, self.y, vx, vy))
+                    .push(Particle::new(self.x, self.y, vx, vy, now, rng))
             });
 
-            self.last_particle = unsafe { SDL_GetTicks64() };
+            self.last_particle = now;
         } else {
             let ms_per_particle =
                 (1000.0 / constants::particle::bullet::PARTICLES_PER_SECOND) as u64;
 
-            if self.last_particle + ms_per_particle < unsafe { SDL_GetTicks64() } {
-                self.last_particle = unsafe { SDL_GetTicks64() };
-                let vel = -rand::rng().random_range(constants::particle::bullet::VEL_RANGE);
+            if self.last_particle + ms_per_particle < now {
+                self.last_particle = now;
+                let vel = -rng.random_range(constants::particle::bullet::VEL_RANGE);
                 let angle = self.vy.atan2(self.vx)
-                    + rand::rng().random_range(
+                    + rng.random_range(
                         -constants::particle::bullet::ANGLE_OFFSET
                             ..constants::particle::bullet::ANGLE_OFFSET,
                     );
@@ -84,7 +84,7 @@ impl Bullet {
                 let vy = vel * angle.sin();
 
                 self.particles_to_spawn
-                    .push(Particle::new(self.x, self.y, vx, vy));
+                    .push(Particle::new(self.x, self.y, vx, vy, now, rng));
             }
         }
 
@@ -113,8 +113,8 @@ impl Bullet {
         Ok(())
     }
 
-    pub fn is_alive(&self) -> bool {
-        self.death >= unsafe { SDL_GetTicks64() }
+    pub fn is_alive(&self, now: u64) -> bool {
+        self.death >= now
     }
 
     pub fn get_particles_to_spawn(&mut self) -> Vec<Particle> {
@@ -134,7 +134,49 @@ impl Bullet {
         (self.x, self.y)
     }
 
+    pub fn get_velocity(&self) -> (f32, f32) {
+        (self.vx, self.vy)
+    }
+
     pub fn get_is_player_shot(&self) -> bool {
-        self.is_player_shot
+        self.owner.is_some()
+    }
+
+    pub fn get_owner(&self) -> Option<usize> {
+        self.owner
+    }
+
+    pub fn set_owner(&mut self, owner: Option<usize>) {
+        self.owner = owner;
+    }
+
+    pub fn write(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.x.to_le_bytes());
+        buf.extend_from_slice(&self.y.to_le_bytes());
+        buf.extend_from_slice(&self.vx.to_le_bytes());
+        buf.extend_from_slice(&self.vy.to_le_bytes());
+        buf.push(self.owner.map(|o| o as u8).unwrap_or(u8::MAX));
+    }
+
+    pub fn read(reader: &mut Reader) -> Option<Bullet> {
+        let x = reader.read_f32()?;
+        let y = reader.read_f32()?;
+        let vx = reader.read_f32()?;
+        let vy = reader.read_f32()?;
+        let owner = reader.read_u8()?;
+
+        Some(Bullet {
+            x,
+            y,
+            vx,
+            vy,
+            death: 0,
+
+            last_particle: 0,
+            particles_to_spawn: Vec::new(),
+
+            to_die: false,
+            owner: (owner != u8::MAX).then_some(owner as usize),
+        })
     }
 }
@@ -0,0 +1,209 @@
+use crate::alien::ShootingType;
+use crate::constants;
+use crate::font;
+use sdl2::keyboard::Keycode;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::{BlendMode, Canvas};
+use sdl2::video::Window;
+
+pub enum Command {
+    SpawnAsteroid { radius: f32, x: f32, y: f32 },
+    SpawnAlien(ShootingType),
+    SpawnBlackHole,
+    SetScore(u64),
+    God,
+    TimeScale(Option<f32>),
+    Seed(Option<u64>),
+    Set(String, Option<String>),
+}
+
+#[derive(Default)]
+pub struct Console {
+    open: bool,
+    line: String,
+
+    history: Vec<String>,
+    history_index: Option<usize>,
+
+    output: Vec<String>,
+}
+
+impl Console {
+    pub fn new() -> Self {
+        Console::default()
+    }
+
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+        self.line.clear();
+        self.history_index = None;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn handle_text(&mut self, text: &str) {
+        if !self.open {
+            return;
+        }
+
+        self.line.extend(
+            text.chars()
+                .filter(|&c| c != constants::console::TOGGLE_CHAR)
+                .flat_map(|c| c.to_lowercase()),
+        );
+    }
+
+    pub fn handle_key_event(&mut self, key: Keycode) -> Option<Command> {
+        match key {
+            Keycode::BACKSPACE => {
+                self.line.pop();
+            }
+            Keycode::UP if !self.history.is_empty() => {
+                let index = self
+                    .history_index
+                    .map_or(self.history.len() - 1, |i| i.saturating_sub(1));
+
+                self.history_index = Some(index);
+                self.line = self.history[index].clone();
+            }
+            Keycode::DOWN => {
+                self.history_index = self.history_index.map(|i| i + 1);
+
+                match self.history_index.and_then(|i| self.history.get(i)) {
+                    Some(line) => self.line = line.clone(),
+                    None => {
+                        self.history_index = None;
+                        self.line.clear();
+                    }
+                }
+            }
+            Keycode::RETURN | Keycode::KP_ENTER => return self.submit(),
+            _ => {}
+        }
+
+        None
+    }
+
+    pub fn print(&mut self, text: &str) {
+        self.output.push(text.to_string());
+
+        let excess = self
+            .output
+            .len()
+            .saturating_sub(constants::console::OUTPUT_LINES);
+        self.output.drain(..excess);
+    }
+
+    fn submit(&mut self) -> Option<Command> {
+        let line = self.line.trim().to_string();
+        self.line.clear();
+        self.history_index = None;
+
+        if line.is_empty() {
+            return None;
+        }
+
+        if self.history.last() != Some(&line) {
+            self.history.push(line.clone());
+        }
+
+        self.print(format!("{}{}", constants::console::PROMPT, line).as_str());
+
+        match Self::parse(&line) {
+            Ok(command) => Some(command),
+            Err(e) => {
+                self.print(e.as_str());
+                None
+            }
+        }
+    }
+
+    fn parse(line: &str) -> Result<Command, String> {
+        let words = line.split_whitespace().collect::<Vec<&str>>();
+
+        let number = |i: usize| -> Result<f32, String> {
+            let word = words
+                .get(i)
+                .ok_or(String::from(constants::strings::MISSING_ARGUMENT_ERROR))?;
+
+            word.parse::<f32>()
+                .ok()
+                .filter(|v| v.is_finite())
+                .ok_or(format!("{}{}", constants::strings::INVALID_VALUE_ERROR, word))
+        };
+
+        let integer = |i: usize| -> Result<u64, String> {
+            let word = words
+                .get(i)
+                .ok_or(String::from(constants::strings::MISSING_ARGUMENT_ERROR))?;
+
+            word.parse::<u64>()
+                .map_err(|_| format!("{}{}", constants::strings::INVALID_VALUE_ERROR, word))
+        };
+
+        let radius = |i: usize| -> Result<f32, String> {
+            let radius = number(i)?;
+
+            if (constants::asteroid::MIN_RADIUS..=constants::asteroid::MAX_RADIUS).contains(&radius) {
+                Ok(radius)
+            } else {
+                Err(format!("{}{}", constants::strings::INVALID_VALUE_ERROR, radius))
+            }
+        };
+
+        match words.as_slice() {
+            ["spawn", "asteroid", ..] => Ok(Command::SpawnAsteroid {
+                radius: radius(2)?,
+                x: number(3)?,
+                y: number(4)?,
+            }),
+            ["spawn", "alien", kind] => Ok(Command::SpawnAlien(match *kind {
+                "random" => ShootingType::Random,
+                "current" => ShootingType::Current,
+                "future" => ShootingType::Future,
+                _ => return Err(format!("{}{}", constants::strings::INVALID_VALUE_ERROR, kind)),
+            })),
+            ["spawn", "blackhole"] => Ok(Command::SpawnBlackHole),
+            ["set", "score", _] => Ok(Command::SetScore(integer(2)?)),
+            ["set", key] => Ok(Command::Set(key.to_string(), None)),
+            ["set", key, value] => Ok(Command::Set(key.to_string(), Some(value.to_string()))),
+            ["god"] => Ok(Command::God),
+            ["timescale"] => Ok(Command::TimeScale(None)),
+            ["timescale", _] => Ok(Command::TimeScale(Some(number(1)?))),
+            ["seed"] => Ok(Command::Seed(None)),
+            ["seed", _] => Ok(Command::Seed(Some(integer(1)?))),
+            _ => Err(format!("{}{}", constants::strings::UNKNOWN_COMMAND_ERROR, line)),
+        }
+    }
+
+    pub fn render(&self, canvas: &mut Canvas<Window>, screen_bounds: Rect) -> Result<(), String> {
+        if !self.open {
+            return Ok(());
+        }
+
+        let line_height = 2 * constants::font::FONT_SIZE + constants::font::MARGIN;
+        let height = (constants::console::OUTPUT_LINES as u32 + 1) * line_height + constants::font::MARGIN;
+
+        canvas.set_blend_mode(BlendMode::Blend);
+        canvas.set_draw_color(Color::RGBA(0, 0, 0, constants::console::BACKGROUND_ALPHA));
+        canvas.fill_rect(Rect::new(0, 0, screen_bounds.width(), height))?;
+        canvas.set_blend_mode(BlendMode::None);
+
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.draw_line((0, height as i32), (screen_bounds.width() as i32, height as i32))?;
+
+        self.output.iter().enumerate().try_for_each(|(i, text)| {
+            font::render_text(text, 10, 10 + (i as u32 * line_height) as i32, canvas)
+        })?;
+
+        font::render_text(
+            format!("{}{}", constants::console::PROMPT, self.line).as_str(),
+            10,
+            10 + (constants::console::OUTPUT_LINES as u32 * line_height) as i32,
+            canvas,
+        )
+    }
+}
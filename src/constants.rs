@@ -13,13 +13,21 @@ pub mod window {
     ];
 }
 
+pub mod session {
+    pub const RNG_STREAM_MULTIPLIER: u64 = 0x9E37_79B9_7F4A_7C15;
+    pub const HASH_OFFSET: u64 = 0xCBF2_9CE4_8422_2325;
+    pub const HASH_PRIME: u64 = 0x0000_0100_0000_01B3;
+}
+
 pub mod player {
     pub const TURN_SPEED: f32 = 7.0;
     pub const ACCELERATION: f32 = 1000.0;
     pub const DECELERATION: f32 = 1.0;
     pub const PLAYER_SHAPE: [[f32; 2]; 4] = [[0.0, -40.0], [15.0, 15.0], [0.0, 0.0], [-15.0, 15.0]];
     pub const START_LIVES: u32 = 3;
+    pub const MAX_PLAYERS: usize = 2;
     pub const POINTS_PER_LIFE: u64 = 1000;
+    pub const RADIUS: f32 = 40.0;
 }
 
 pub mod particle {
@@ -61,6 +69,8 @@ pub mod asteroid {
     pub const SPAWN_RADIUS_RANGE: Range<f64> = 70.0..120.0;
     pub const RADIUS_OFFSET_FAC: f32 = 0.4;
     pub const POINTS_PER_RADIUS: f32 = 0.2;
+    pub const MAX_POINTS: f32 = u8::MAX as f32;
+    pub const MAX_RADIUS: f32 = MAX_POINTS / POINTS_PER_RADIUS;
     pub const VEL_RANGE: Range<f32> = 10.0..50.0;
     pub const MAX_SPAWN_ATTEMPTS: u32 = 10;
     pub const MIN_SPAWN_DISTANCE: f32 = 500.0 * 500.0;
@@ -81,8 +91,18 @@ pub mod asteroid {
 }
 
 pub mod font {
+    pub type Glyph = &'static [[(f32, f32); 2]];
+
     pub const FONT_SIZE: u32 = 30;
     pub const MARGIN: u32 = 15;
+    pub const LINE_SPACING: u32 = 15;
+    pub const SHIP_ICON: char = '\u{E000}';
+    pub const ALIEN_ICON: char = '\u{E001}';
+    pub const ASTEROID_ICON: char = '\u{E002}';
+    pub const HEART_ICON: char = '♥';
+    pub const STAR_ICON: char = '★';
+    pub const CHECK_ICON: char = '✓';
+    pub const CROSS_ICON: char = '✗';
     pub const NUMBERS: [&[[(f32, f32); 2]]; 10] = [
         &[
             [(0.0, 0.0), (1.0, 0.0)],
@@ -304,15 +324,360 @@ pub mod font {
         [(0.5, 0.0), (1.0, 2.0)],
         [(0.25, 1.0), (0.75, 1.0)],
     ];
+
+    pub const ACUTE: Glyph = &[[(0.4, -0.2), (0.7, -0.6)]];
+    pub const GRAVE: Glyph = &[[(0.3, -0.6), (0.6, -0.2)]];
+    pub const CIRCUMFLEX: Glyph = &[[(0.2, -0.2), (0.5, -0.6)], [(0.5, -0.6), (0.8, -0.2)]];
+    pub const TILDE: Glyph = &[
+        [(0.1, -0.3), (0.35, -0.5)],
+        [(0.35, -0.5), (0.65, -0.3)],
+        [(0.65, -0.3), (0.9, -0.5)],
+    ];
+    pub const DIAERESIS: Glyph = &[[(0.25, -0.5), (0.25, -0.3)], [(0.75, -0.5), (0.75, -0.3)]];
+    pub const RING: Glyph = &[
+        [(0.35, -0.6), (0.65, -0.6)],
+        [(0.65, -0.6), (0.65, -0.2)],
+        [(0.65, -0.2), (0.35, -0.2)],
+        [(0.35, -0.2), (0.35, -0.6)],
+    ];
+    pub const CEDILLA: Glyph = &[[(0.5, 2.0), (0.5, 2.2)], [(0.5, 2.2), (0.3, 2.4)]];
+    pub const STROKE: Glyph = &[[(1.0, 0.0), (0.0, 2.0)]];
+    pub const BAR: Glyph = &[[(-0.2, 1.0), (0.4, 1.0)]];
+
+    pub const ACCENTED: &[(&str, &str, Glyph)] = &[
+        ("ÀÈÌÒÙàèìòù", "AEIOUaeiou", GRAVE),
+        ("ÁÉÍÓÚÝáéíóúý", "AEIOUYaeiouy", ACUTE),
+        ("ÂÊÎÔÛâêîôû", "AEIOUaeiou", CIRCUMFLEX),
+        ("ÃÑÕãñõ", "ANOano", TILDE),
+        ("ÄËÏÖÜäëïöüÿ", "AEIOUaeiouy", DIAERESIS),
+        ("Åå", "Aa", RING),
+        ("Çç", "Cc", CEDILLA),
+        ("Øø", "Oo", STROKE),
+        ("Ðð", "Dd", BAR),
+    ];
+
+    pub const SYMBOLS: &[(char, Glyph)] = &[
+        ('.', &[[(0.5, 1.8), (0.5, 2.0)]]),
+        (',', &[[(0.5, 1.7), (0.5, 2.0)], [(0.5, 2.0), (0.3, 2.3)]]),
+        (':', &[[(0.5, 0.4), (0.5, 0.6)], [(0.5, 1.8), (0.5, 2.0)]]),
+        ('!', &[[(0.5, 0.0), (0.5, 1.4)], [(0.5, 1.8), (0.5, 2.0)]]),
+        (
+            '?',
+            &[
+                [(0.0, 0.0), (1.0, 0.0)],
+                [(1.0, 0.0), (1.0, 1.0)],
+                [(1.0, 1.0), (0.5, 1.0)],
+                [(0.5, 1.0), (0.5, 1.4)],
+                [(0.5, 1.8), (0.5, 2.0)],
+            ],
+        ),
+        ('-', &[[(0.2, 1.0), (0.8, 1.0)]]),
+        ('/', &[[(1.0, 0.0), (0.0, 2.0)]]),
+        ('\'', &[[(0.5, 0.0), (0.5, 0.5)]]),
+        (
+            '%',
+            &[
+                [(1.0, 0.0), (0.0, 2.0)],
+                [(0.0, 0.0), (0.3, 0.0)],
+                [(0.3, 0.0), (0.3, 0.5)],
+                [(0.3, 0.5), (0.0, 0.5)],
+                [(0.0, 0.5), (0.0, 0.0)],
+                [(0.7, 1.5), (1.0, 1.5)],
+                [(1.0, 1.5), (1.0, 2.0)],
+                [(1.0, 2.0), (0.7, 2.0)],
+                [(0.7, 2.0), (0.7, 1.5)],
+            ],
+        ),
+        ('×', &[[(0.2, 0.9), (0.8, 1.7)], [(0.8, 0.9), (0.2, 1.7)]]),
+        ('+', &[[(0.5, 0.6), (0.5, 1.4)], [(0.1, 1.0), (0.9, 1.0)]]),
+        ('=', &[[(0.1, 0.7), (0.9, 0.7)], [(0.1, 1.3), (0.9, 1.3)]]),
+        ('<', &[[(1.0, 0.4), (0.0, 1.0)], [(0.0, 1.0), (1.0, 1.6)]]),
+        ('>', &[[(0.0, 0.4), (1.0, 1.0)], [(1.0, 1.0), (0.0, 1.6)]]),
+        (
+            '(',
+            &[
+                [(0.7, 0.0), (0.3, 0.5)],
+                [(0.3, 0.5), (0.3, 1.5)],
+                [(0.3, 1.5), (0.7, 2.0)],
+            ],
+        ),
+        (
+            ')',
+            &[
+                [(0.3, 0.0), (0.7, 0.5)],
+                [(0.7, 0.5), (0.7, 1.5)],
+                [(0.7, 1.5), (0.3, 2.0)],
+            ],
+        ),
+        ('_', &[[(0.0, 2.0), (1.0, 2.0)]]),
+        ('¡', &[[(0.5, 0.0), (0.5, 0.2)], [(0.5, 0.6), (0.5, 2.0)]]),
+        (
+            '¿',
+            &[
+                [(0.5, 0.0), (0.5, 0.2)],
+                [(0.5, 0.6), (0.5, 1.0)],
+                [(0.5, 1.0), (0.0, 1.0)],
+                [(0.0, 1.0), (0.0, 2.0)],
+                [(0.0, 2.0), (1.0, 2.0)],
+            ],
+        ),
+        (
+            '«',
+            &[
+                [(0.5, 0.6), (0.1, 1.0)],
+                [(0.1, 1.0), (0.5, 1.4)],
+                [(0.9, 0.6), (0.5, 1.0)],
+                [(0.5, 1.0), (0.9, 1.4)],
+            ],
+        ),
+        (
+            '»',
+            &[
+                [(0.1, 0.6), (0.5, 1.0)],
+                [(0.5, 1.0), (0.1, 1.4)],
+                [(0.5, 0.6), (0.9, 1.0)],
+                [(0.9, 1.0), (0.5, 1.4)],
+            ],
+        ),
+        (
+            '°',
+            &[
+                [(0.3, 0.0), (0.7, 0.0)],
+                [(0.7, 0.0), (0.7, 0.4)],
+                [(0.7, 0.4), (0.3, 0.4)],
+                [(0.3, 0.4), (0.3, 0.0)],
+            ],
+        ),
+        (
+            'Æ',
+            &[
+                [(0.0, 2.0), (0.5, 0.0)],
+                [(0.5, 0.0), (1.0, 0.0)],
+                [(0.5, 0.0), (0.5, 2.0)],
+                [(0.5, 2.0), (1.0, 2.0)],
+                [(0.25, 1.0), (0.9, 1.0)],
+            ],
+        ),
+        (
+            'æ',
+            &[
+                [(0.0, 2.0), (0.5, 0.0)],
+                [(0.5, 0.0), (1.0, 0.0)],
+                [(0.5, 0.0), (0.5, 2.0)],
+                [(0.5, 2.0), (1.0, 2.0)],
+                [(0.25, 1.0), (0.9, 1.0)],
+            ],
+        ),
+        (
+            'ß',
+            &[
+                [(0.0, 2.0), (0.0, 0.0)],
+                [(0.0, 0.0), (0.8, 0.0)],
+                [(0.8, 0.0), (0.8, 0.8)],
+                [(0.8, 0.8), (0.3, 1.0)],
+                [(0.3, 1.0), (1.0, 1.4)],
+                [(1.0, 1.4), (1.0, 2.0)],
+                [(1.0, 2.0), (0.4, 2.0)],
+            ],
+        ),
+        (
+            'Þ',
+            &[
+                [(0.0, 0.0), (0.0, 2.0)],
+                [(0.0, 0.4), (1.0, 0.4)],
+                [(1.0, 0.4), (1.0, 1.4)],
+                [(1.0, 1.4), (0.0, 1.4)],
+            ],
+        ),
+        (
+            'þ',
+            &[
+                [(0.0, 0.0), (0.0, 2.0)],
+                [(0.0, 0.4), (1.0, 0.4)],
+                [(1.0, 0.4), (1.0, 1.4)],
+                [(1.0, 1.4), (0.0, 1.4)],
+            ],
+        ),
+        (
+            '←',
+            &[
+                [(0.0, 1.0), (1.0, 1.0)],
+                [(0.0, 1.0), (0.4, 0.6)],
+                [(0.0, 1.0), (0.4, 1.4)],
+            ],
+        ),
+        (
+            '→',
+            &[
+                [(0.0, 1.0), (1.0, 1.0)],
+                [(1.0, 1.0), (0.6, 0.6)],
+                [(1.0, 1.0), (0.6, 1.4)],
+            ],
+        ),
+        (
+            '↑',
+            &[
+                [(0.5, 0.0), (0.5, 2.0)],
+                [(0.5, 0.0), (0.0, 0.5)],
+                [(0.5, 0.0), (1.0, 0.5)],
+            ],
+        ),
+        (
+            '↓',
+            &[
+                [(0.5, 0.0), (0.5, 2.0)],
+                [(0.5, 2.0), (0.0, 1.5)],
+                [(0.5, 2.0), (1.0, 1.5)],
+            ],
+        ),
+        (SHIP_ICON, LIFE_CHAR),
+        (
+            ALIEN_ICON,
+            &[
+                [(0.2, 1.0), (0.8, 1.0)],
+                [(0.0, 1.3), (1.0, 1.3)],
+                [(0.2, 1.0), (0.0, 1.3)],
+                [(0.8, 1.0), (1.0, 1.3)],
+                [(0.0, 1.3), (0.3, 1.6)],
+                [(0.3, 1.6), (0.7, 1.6)],
+                [(0.7, 1.6), (1.0, 1.3)],
+                [(0.35, 1.0), (0.4, 0.7)],
+                [(0.4, 0.7), (0.6, 0.7)],
+                [(0.6, 0.7), (0.65, 1.0)],
+            ],
+        ),
+        (
+            ASTEROID_ICON,
+            &[
+                [(0.3, 0.5), (0.7, 0.4)],
+                [(0.7, 0.4), (1.0, 0.8)],
+                [(1.0, 0.8), (0.9, 1.3)],
+                [(0.9, 1.3), (1.0, 1.6)],
+                [(1.0, 1.6), (0.6, 2.0)],
+                [(0.6, 2.0), (0.2, 1.9)],
+                [(0.2, 1.9), (0.0, 1.4)],
+                [(0.0, 1.4), (0.1, 0.9)],
+                [(0.1, 0.9), (0.3, 0.5)],
+            ],
+        ),
+        (
+            HEART_ICON,
+            &[
+                [(0.5, 2.0), (0.0, 1.0)],
+                [(0.0, 1.0), (0.0, 0.6)],
+                [(0.0, 0.6), (0.25, 0.4)],
+                [(0.25, 0.4), (0.5, 0.6)],
+                [(0.5, 0.6), (0.75, 0.4)],
+                [(0.75, 0.4), (1.0, 0.6)],
+                [(1.0, 0.6), (1.0, 1.0)],
+                [(1.0, 1.0), (0.5, 2.0)],
+            ],
+        ),
+        (
+            STAR_ICON,
+            &[
+                [(0.5, 0.4), (0.65, 0.9)],
+                [(0.65, 0.9), (1.0, 0.9)],
+                [(1.0, 0.9), (0.7, 1.2)],
+                [(0.7, 1.2), (0.85, 1.7)],
+                [(0.85, 1.7), (0.5, 1.4)],
+                [(0.5, 1.4), (0.15, 1.7)],
+                [(0.15, 1.7), (0.3, 1.2)],
+                [(0.3, 1.2), (0.0, 0.9)],
+                [(0.0, 0.9), (0.35, 0.9)],
+                [(0.35, 0.9), (0.5, 0.4)],
+            ],
+        ),
+        (CHECK_ICON, &[[(0.0, 1.2), (0.35, 1.8)], [(0.35, 1.8), (1.0, 0.4)]]),
+        (CROSS_ICON, &[[(0.0, 0.6), (1.0, 1.8)], [(1.0, 0.6), (0.0, 1.8)]]),
+    ];
 }
 
 pub mod strings {
     pub const HIGH_SCORE_PATH: &str = "highscore.bin";
     pub const RENDER_ERROR: &str = "Error rendering game: ";
     pub const HIGH_SCORE_ERROR: &str = "Error saving high score: ";
-    pub const START_TEXT: &str = "press space to start";
+    pub const TITLE_TEXT: &str = "asteroids";
+    pub const PLAYER_TEXT: &str = "player";
+    pub const READY_TEXT: &str = "ready";
     pub const WINDOW_SIZE_ERROR: &str = "Window too small";
     pub const PAUSED_TEXT: &str = "game paused";
+    pub const CONNECT_ERROR: &str = "Could not connect to server";
+    pub const NETWORK_ERROR: &str = "Network error: ";
+    pub const SNAPSHOT_SIZE_ERROR: &str = "Snapshot too large to send, bytes: ";
+    pub const SERVER_LISTENING: &str = "Server listening on port ";
+    pub const CLIENT_JOINED: &str = "Client joined: ";
+    pub const CLIENT_LEFT: &str = "Client left: ";
+    pub const SPECTATOR_JOINED: &str = "Spectator joined: ";
+    pub const BROADCASTING_TEXT: &str = "Accepting spectators on port ";
+    pub const MISSING_BROADCAST_ERROR: &str = "Missing port after --broadcast";
+    pub const MISSING_SPECTATE_ERROR: &str = "Missing address after --spectate";
+    pub const SPECTATING_TEXT: &str = "spectating";
+    pub const RANDOM_LABEL: &str = "random";
+    pub const CURRENT_LABEL: &str = "current";
+    pub const FUTURE_LABEL: &str = "future";
+    pub const BLACK_HOLE_LABEL: &str = "black hole";
+    pub const MISSING_ADDRESS_ERROR: &str = "Missing address after --connect";
+    pub const MISSING_PEER_ERROR: &str = "Usage: --peer <local port> <remote address> --player <0|1>";
+    pub const USAGE_TEXT: &str = "Usage: Asteroids [options]
+  --connect <address>                 join a dedicated server
+  --spectate <address>                watch a dedicated server or a game started with --broadcast;
+                                      peer-to-peer games can't be spectated
+  --broadcast <port>                  let spectators watch local games on this port
+  --peer <local port> <remote address> --player <0|1> [--seed <seed>]
+                                      play peer-to-peer with rollback
+  --loss <0-1> --latency <ms> --jitter <ms>
+                                      simulate a bad connection in peer-to-peer play
+  --help                              show this message";
+    pub const DESYNC_TEXT: &str = "Desync detected at frame ";
+    pub const SEED_MISMATCH_ERROR: &str = "Peer is using a different seed: ";
+    pub const NO_DESYNC_TEXT: &str = "No desync, last verified frame ";
+    pub const DEBUG_ASTEROIDS: &str = "asteroids";
+    pub const DEBUG_ALIENS: &str = "aliens";
+    pub const DEBUG_BULLETS: &str = "bullets";
+    pub const DEBUG_BLACK_HOLES: &str = "black holes";
+    pub const DEBUG_PARTICLES: &str = "particles";
+    pub const DEBUG_FPS: &str = "fps";
+    pub const INVALID_VALUE_ERROR: &str = "invalid value ";
+    pub const UNKNOWN_KEY_ERROR: &str = "unknown key ";
+    pub const MISSING_ARGUMENT_ERROR: &str = "missing argument";
+    pub const UNKNOWN_COMMAND_ERROR: &str = "unknown command ";
+    pub const OK_TEXT: &str = "ok";
+    pub const GOD_ON_TEXT: &str = "god mode on";
+    pub const GOD_OFF_TEXT: &str = "god mode off";
+    pub const TIME_SCALE_TEXT: &str = "timescale ";
+    pub const SEED_TEXT: &str = "seed ";
+    pub const SOAK_INVALID_STATE_ERROR: &str = "Invalid player state at frame ";
+    pub const SETTINGS_TEXT: &str = "settings";
+    pub const ON_TEXT: &str = "on";
+    pub const OFF_TEXT: &str = "off";
+    pub const CONTROLLER_ERROR: &str = "Error opening controller: ";
+    pub const SETTINGS_ERROR: &str = "Error loading settings: ";
+    pub const SAVE_SETTINGS_ERROR: &str = "Error saving settings: ";
+    pub const CONFIG_DIR_ERROR: &str = "Could not find a config directory";
+    pub const PRESS_KEY_TEXT: &str = "press a key";
+    pub const UNKNOWN_LOCALE_ERROR: &str = "unknown locale ";
+    pub const AUDIO_ERROR: &str = "Error opening audio device: ";
+    pub const STATS_ERROR: &str = "Error loading stats: ";
+    pub const SAVE_STATS_ERROR: &str = "Error saving stats: ";
+    pub const STATS_TEXT: &str = "stats";
+    pub const GAME_OVER_TEXT: &str = "game over";
+    pub const ACHIEVEMENTS_ERROR: &str = "Error loading achievements: ";
+    pub const SAVE_ACHIEVEMENTS_ERROR: &str = "Error saving achievements: ";
+    pub const ACHIEVEMENTS_TEXT: &str = "achievements";
+    pub const ACHIEVEMENT_UNLOCKED_TEXT: &str = "achievement unlocked";
+    pub const DAILY_TEXT: &str = "daily challenge";
+    pub const LEADERBOARDS_TEXT: &str = "leaderboards";
+    pub const LEADERBOARDS_ERROR: &str = "Error loading leaderboards: ";
+    pub const SAVE_LEADERBOARDS_ERROR: &str = "Error saving leaderboards: ";
+    pub const DAILY_ERROR: &str = "Error loading daily results: ";
+    pub const SAVE_DAILY_ERROR: &str = "Error saving daily results: ";
+    pub const SAVE_REPLAY_ERROR: &str = "Error saving replay: ";
+    pub const INVALID_REPLAY_ERROR: &str = "Invalid replay file: ";
+    pub const REPLAY_SEED_ERROR: &str = "Replay seed does not match its date";
+    pub const REPLAY_MISMATCH_ERROR: &str = "Replay does not reproduce its result: ";
+    pub const MISSING_REPLAY_ERROR: &str = "Usage: asteroids-verify-replay <file>";
+    pub const REPLAY_VERIFIED_TEXT: &str = "Replay verified";
 }
 
 pub mod alien {
@@ -329,6 +694,7 @@ pub mod alien {
     ];
     pub const MAX_ALIENS: u32 = 3;
     pub const SHOOT_RADIUS: f32 = 50.0;
+    pub const RADIUS: f32 = 50.0;
     pub mod random {
         use std::ops::Range;
 
@@ -404,6 +770,7 @@ pub mod black_hole {
     use std::ops::Range;
 
     pub const MIN_POINTS: u64 = 5_000;
+    pub const MAX_BLACK_HOLES: u32 = 8;
     pub const MAX_RADII_RANGE: Range<f32> = 100.0..150.0;
     pub const MAX_TIME_RANGE: Range<u64> = 0..10_000;
     pub const GROWTH_RATE: f32 = 5.0;
@@ -426,4 +793,401 @@ pub mod black_hole {
     pub const LINES: u32 = 6;
     pub const LINE_ROT: f32 = PI;
     pub const LINE_RES: u32 = 32;
-}
\ No newline at end of file
+}
+pub mod net {
+    pub const DEFAULT_PORT: u16 = 7777;
+    pub const MAGIC: u16 = 0xA57E;
+    pub const FIELD_WIDTH: u32 = 1920;
+    pub const FIELD_HEIGHT: u32 = 1080;
+    pub const TICK_MS: u64 = 16;
+    pub const MAX_CATCH_UP: f32 = 0.25;
+    pub const SNAPSHOT_INTERVAL: u32 = 2;
+    pub const SNAPSHOT_HISTORY: usize = 64;
+    pub const INPUT_REDUNDANCY: usize = 8;
+    pub const MAX_INPUT_QUEUE: usize = 8;
+    pub const MIN_ZERO_RUN: usize = 4;
+    pub const TIMEOUT_MS: u64 = 5000;
+    pub const CONNECT_ATTEMPTS: u32 = 10;
+    pub const CONNECT_RETRY_MS: u64 = 500;
+    pub const MAX_PACKET_SIZE: usize = 65507;
+    pub const MAX_PENDING_INPUTS: usize = 256;
+}
+
+pub mod autopilot {
+    pub const EVADE_TIME: f32 = 0.6;
+    pub const ENGAGE_DISTANCE: f32 = 600.0;
+    pub const CRUISE_SPEED: f32 = 150.0;
+    pub const TURN_COST: f32 = 200.0;
+    pub const TURN_TOLERANCE: f32 = 0.05;
+    pub const THRUST_TOLERANCE: f32 = 0.5;
+    pub const MIN_FIRE_TOLERANCE: f32 = 0.02;
+    pub const RANDOM_PRIORITY: f32 = 2.0;
+    pub const CURRENT_PRIORITY: f32 = 3.0;
+    pub const FUTURE_PRIORITY: f32 = 4.0;
+    pub const SOAK_FRAMES: u64 = 36_000;
+    pub const SOAK_SESSIONS: u64 = 4;
+    pub const DEFAULT_SEED: u64 = 1979;
+}
+
+pub mod console {
+    pub const TOGGLE_CHAR: char = '`';
+    pub const PROMPT: &str = "> ";
+    pub const OUTPUT_LINES: usize = 6;
+    pub const BACKGROUND_ALPHA: u8 = 200;
+}
+
+pub mod tuning {
+    pub const MIN_SPAWN_RATE: f32 = 0.01;
+    pub const MIN_TIME_SCALE: f32 = 0.1;
+    pub const MAX_TIME_SCALE: f32 = 4.0;
+    pub const TIME_SCALES: [f32; 7] = [0.1, 0.25, 0.5, 1.0, 1.5, 2.0, 4.0];
+    pub const STEP_DT: f32 = 1.0 / 60.0;
+}
+
+pub mod debug {
+    use sdl2::pixels::Color;
+
+    pub const HITBOX_COLOR: Color = Color::RGB(255, 64, 64);
+    pub const TRAIL_COLOR: Color = Color::RGB(255, 255, 0);
+    pub const RANGE_COLOR: Color = Color::RGB(160, 64, 255);
+    pub const VELOCITY_COLOR: Color = Color::RGB(64, 255, 64);
+    pub const GRAPH_COLOR: Color = Color::RGB(64, 160, 255);
+    pub const TARGET_COLOR: Color = Color::RGB(255, 255, 255);
+    pub const VELOCITY_SCALE: f32 = 0.5;
+    pub const CIRCLE_SEGMENTS: u32 = 48;
+    pub const FRAME_HISTORY: usize = 120;
+    pub const GRAPH_BAR_WIDTH: u32 = 3;
+    pub const GRAPH_SCALE: f32 = 4.0;
+    pub const TARGET_FRAME_MS: f32 = 1000.0 / 60.0;
+    pub const TEXT_Y: i32 = 160;
+}
+
+pub mod env {
+    pub const DEFAULT_FRAME_SKIP: u32 = 4;
+    pub const ACTION_COUNT: usize = 16;
+    pub const POINT_REWARD: f32 = 1.0;
+    pub const LIFE_LOST_REWARD: f32 = -100.0;
+    pub const EXTRA_LIFE_REWARD: f32 = 0.0;
+    pub const STEP_REWARD: f32 = 0.0;
+    pub const CIRCLE_SEGMENTS: u32 = 16;
+}
+
+pub mod spectator {
+    pub const PAN_SPEED: f32 = 900.0;
+    pub const ZOOM_SPEED: f32 = 2.0;
+    pub const MIN_ZOOM: f32 = 1.0;
+    pub const MAX_ZOOM: f32 = 4.0;
+    pub const LABEL_OFFSET: i32 = 20;
+}
+
+pub mod rollback {
+    pub const DEFAULT_PORT: u16 = 7778;
+    pub const DEFAULT_SEED: u64 = 1979;
+    pub const INPUT_DELAY: u32 = 2;
+    pub const MAX_ROLLBACK: u32 = 8;
+    pub const MAX_INPUTS_PER_PACKET: usize = 32;
+    pub const HASH_REDUNDANCY: usize = 8;
+    pub const HASH_HISTORY: u32 = 256;
+    pub const TEST_FRAMES: u32 = 3600;
+    pub const TEST_SETTLE_FRAMES: u32 = 120;
+}
+
+pub mod main_menu {
+    pub const ITEMS: [&str; 10] = [
+        "one player",
+        "two players",
+        "daily challenge",
+        "sandbox",
+        "tutorial",
+        "leaderboards",
+        "stats",
+        "achievements",
+        "settings",
+        "exit",
+    ];
+}
+
+pub mod achievements {
+    pub const DEFINITIONS: &str = include_str!("../data/achievements.txt");
+    pub const FILE: &str = "achievements.cfg";
+    pub const TOAST_DURATION: f32 = 3.0;
+    pub const TOAST_SCALE: f32 = 0.6;
+    pub const TOAST_TOP: i32 = 100;
+}
+
+pub mod mode {
+    pub const COUNT: usize = 5;
+    pub const NAMES: [&str; COUNT] = ["classic", "time attack", "survival", "zen", "hardcore"];
+    pub const KEYS: [&str; COUNT] = ["classic", "time_attack", "survival", "zen", "hardcore"];
+    pub const FILE: &str = "leaderboards.cfg";
+    pub const LABEL: &str = "mode";
+    pub const TIME_ATTACK_MS: u64 = 180_000;
+    pub const SURVIVAL_SPAWN_RAMP: f32 = 0.01;
+    pub const HARDCORE_LIVES: u32 = 1;
+    pub const LEADERBOARD_SIZE: usize = 10;
+    pub const TIMER_SCALE: f32 = 0.8;
+}
+
+pub mod sandbox {
+    use std::ops::Range;
+
+    pub const TITLE: &str = "sandbox";
+    pub const LABELS: [&str; 14] = [
+        "small asteroids",
+        "medium asteroids",
+        "large asteroids",
+        "asteroids per minute",
+        "random aliens",
+        "current aliens",
+        "future aliens",
+        "aliens per minute",
+        "black holes",
+        "black holes per minute",
+        "invulnerability",
+        "infinite lives",
+        "start",
+        "back",
+    ];
+    pub const ASTEROID_RADII: [Range<f32>; 3] = [20.0..40.0, 40.0..80.0, 80.0..120.0];
+    pub const ASTEROID_RATES: [f32; 6] = [5.0, 10.0, 20.0, 30.0, 45.0, 60.0];
+    pub const ALIEN_RATES: [f32; 6] = [1.0, 2.0, 4.0, 6.0, 10.0, 20.0];
+    pub const BLACK_HOLE_RATES: [f32; 5] = [0.5, 1.0, 2.0, 4.0, 6.0];
+    pub const DEFAULT_ASTEROID_RATE: usize = 2;
+    pub const DEFAULT_ALIEN_RATE: usize = 2;
+    pub const DEFAULT_BLACK_HOLE_RATE: usize = 1;
+    pub const MS_PER_MINUTE: f32 = 60_000.0;
+    pub const MENU_SCALE: f32 = 0.6;
+}
+
+pub mod attract {
+    pub const IDLE_SECONDS: f32 = 20.0;
+    pub const PAGE_SECONDS: [f32; 3] = [8.0, 8.0, 30.0];
+    pub const HIGH_SCORES_TEXT: &str = "high scores";
+    pub const CONTROLS_TEXT: &str = "controls";
+    pub const DEMO_TEXT: &str = "demo";
+    pub const PRESS_ANY_KEY_TEXT: &str = "press any key";
+    pub const CONTROL_LABELS: [&str; 5] = ["left", "right", "thrust", "fire", "pause"];
+    pub const PAUSE_KEYS: &str = "P / Escape";
+    pub const EMPTY_SCORE: &str = "-";
+    pub const TITLE_SCALE: f32 = 2.0;
+    pub const TEXT_SCALE: f32 = 0.6;
+    pub const BLINK_SECONDS: f32 = 0.5;
+    pub const BOTTOM_MARGIN: i32 = 100;
+}
+
+pub mod tutorial {
+    use std::f32::consts::PI;
+
+    pub const PROMPTS: [&str; 7] = [
+        "rotate your ship with left and right",
+        "press up to thrust forward",
+        "turn around and thrust to stop",
+        "shoot the asteroid",
+        "fly off the edge of the screen",
+        "stay out of the black hole",
+        "tutorial complete",
+    ];
+    pub const STEP_TEXT: &str = "step";
+    pub const CAUGHT_TEXT: &str = "caught by the black hole";
+    pub const ROTATION: f32 = PI;
+    pub const THRUST_SPEED: f32 = 150.0;
+    pub const STOP_SPEED: f32 = 20.0;
+    pub const ASTEROID_RADIUS: f32 = 30.0;
+    pub const ASTEROID_DISTANCE: f32 = 300.0;
+    pub const BLACK_HOLE_DISTANCE: f32 = 400.0;
+    pub const AVOID_SECONDS: f32 = 15.0;
+    pub const COMPLETE_DELAY: f32 = 1.5;
+    pub const DONE_SECONDS: f32 = 3.0;
+    pub const CAUGHT_SECONDS: f32 = 2.0;
+    pub const TEXT_SCALE: f32 = 0.6;
+    pub const BOTTOM_MARGIN: i32 = 150;
+}
+
+pub mod daily {
+    pub const FILE: &str = "daily.cfg";
+    pub const REPLAY_DIRECTORY: &str = "replays";
+    pub const REPLAY_EXTENSION: &str = "replay";
+    pub const REPLAY_MAGIC: u16 = 0xDA17;
+    pub const REPLAY_VERSION: u8 = 1;
+    pub const SEED_PREFIX: &str = "daily-";
+    pub const SECONDS_PER_DAY: u64 = 86400;
+    pub const LIVES: u32 = 3;
+    pub const LEADERBOARD_SIZE: usize = 10;
+    pub const PLAY_LABEL: &str = "play";
+    pub const TODAY_TEXT: &str = "today";
+    pub const PLAYED_TEXT: &str = "come back tomorrow";
+    pub const PENDING_TEXT: &str = "...";
+}
+
+pub mod stats {
+    pub const FILE: &str = "stats.cfg";
+    pub const KEYS: [&str; 15] = [
+        "games_played",
+        "shots_fired",
+        "shots_hit",
+        "asteroids_small",
+        "asteroids_medium",
+        "asteroids_large",
+        "aliens_random",
+        "aliens_current",
+        "aliens_future",
+        "deaths_asteroid",
+        "deaths_alien_bullet",
+        "deaths_black_hole",
+        "time_survived",
+        "distance_flown",
+        "max_combo",
+    ];
+    pub const LABELS: [&str; 15] = [
+        "games played",
+        "shots fired",
+        "accuracy",
+        "small asteroids",
+        "medium asteroids",
+        "large asteroids",
+        "random aliens",
+        "current aliens",
+        "future aliens",
+        "asteroid deaths",
+        "alien bullet deaths",
+        "black hole deaths",
+        "time survived",
+        "distance flown",
+        "max combo",
+    ];
+    pub const GAME_OVER_ITEMS: [&str; 2] = ["play again", "main menu"];
+    pub const COMBO_WINDOW: f32 = 2.0;
+    pub const DISTANCE_UNIT: f32 = 100.0;
+    pub const TABLE_SCALE: f32 = 0.4;
+    pub const COLUMN_WIDTH: u32 = 500;
+    pub const TOP_MARGIN_DIVISOR: i32 = 12;
+}
+
+pub mod settings {
+    use std::ops::RangeInclusive;
+
+    pub const DIRECTORY: &str = "asteroids";
+    pub const FILE: &str = "settings.cfg";
+    pub const KEYS: [&str; 16] = [
+        "fullscreen",
+        "vsync",
+        "resolution",
+        "master_volume",
+        "sfx_volume",
+        "key_left",
+        "key_right",
+        "key_thrust",
+        "key_fire",
+        "difficulty",
+        "autofire",
+        "start_lives",
+        "attract_ship",
+        "large_text",
+        "game_speed",
+        "locale",
+    ];
+    pub const CATEGORIES: [&str; 8] = [
+        "video",
+        "audio",
+        "controls",
+        "gameplay",
+        "accessibility",
+        "language",
+        "reset to defaults",
+        "back",
+    ];
+    pub const LABELS: [&str; 16] = [
+        "fullscreen",
+        "vsync",
+        "resolution",
+        "master volume",
+        "effects volume",
+        "left",
+        "right",
+        "thrust",
+        "fire",
+        "difficulty",
+        "autofire",
+        "starting lives",
+        "large text",
+        "game speed",
+        "language",
+        "attract mode ship",
+    ];
+    pub const RESOLUTIONS: [(u32, u32); 4] = [(1280, 720), (1600, 900), (1920, 1080), (2560, 1440)];
+    pub const DIFFICULTY_NAMES: [&str; 3] = ["easy", "normal", "hard"];
+    pub const DIFFICULTY_SPAWN_RATES: [f32; 3] = [0.75, 1.0, 1.5];
+    pub const DIFFICULTY_MAX_ALIENS: [u32; 3] = [2, 3, 4];
+    pub const START_LIVES: RangeInclusive<u32> = 1..=5;
+    pub const GAME_SPEEDS: [f32; 3] = [0.5, 0.75, 1.0];
+    pub const VOLUME_STEP: f32 = 0.1;
+    pub const AUTOFIRE_RATE: f32 = 6.0;
+    pub const LARGE_TEXT_SCALE: f32 = 1.25;
+}
+
+pub mod audio {
+    pub const SAMPLE_RATE: u32 = 44100;
+    pub const CHANNELS: u16 = 2;
+    pub const BUFFER_SAMPLES: u16 = 1024;
+    pub const MAX_VOICES: usize = 32;
+    pub const NOISE_SEED: u32 = 0x2545_F491;
+
+    pub const STEREO_WIDTH: f32 = 0.8;
+    pub const MIN_GAIN: f32 = 0.3;
+    pub const SMOOTHING: f32 = 0.0005;
+
+    pub const FIRE_DURATION: f32 = 0.12;
+    pub const FIRE_FREQUENCIES: (f32, f32) = (1400.0, 300.0);
+    pub const FIRE_GAIN: f32 = 0.2;
+
+    pub const THRUST_DURATION: f32 = 1.0;
+    pub const THRUST_CUTOFF: f32 = 300.0;
+    pub const THRUST_GAIN: f32 = 0.8;
+
+    pub const MEDIUM_EXPLOSION_RADIUS: f32 = 40.0;
+    pub const LARGE_EXPLOSION_RADIUS: f32 = 80.0;
+    pub const EXPLOSION_DURATIONS: [f32; 3] = [0.3, 0.6, 1.0];
+    pub const EXPLOSION_CUTOFFS: [f32; 3] = [2400.0, 1200.0, 500.0];
+    pub const EXPLOSION_GAINS: [f32; 3] = [1.0, 1.5, 2.2];
+
+    pub const SIREN_DURATION: f32 = 1.0;
+    pub const SIREN_FREQUENCIES: [(f32, f32); 3] = [(400.0, 600.0), (600.0, 900.0), (900.0, 1300.0)];
+    pub const SIREN_RATES: [f32; 3] = [4.0, 6.0, 8.0];
+    pub const SIREN_GAIN: f32 = 0.1;
+
+    pub const DRONE_DURATION: f32 = 2.0;
+    pub const DRONE_FREQUENCIES: [f32; 3] = [27.5, 55.0, 55.5];
+    pub const DRONE_GAIN: f32 = 0.4;
+    pub const DRONE_RADIUS_SCALE: f32 = 2.0;
+
+    pub const EXTRA_LIFE_NOTES: [f32; 4] = [1047.0, 1319.0, 1568.0, 2093.0];
+    pub const EXTRA_LIFE_NOTE_DURATION: f32 = 0.08;
+    pub const EXTRA_LIFE_GAIN: f32 = 0.2;
+
+    pub const HEARTBEAT_FREQUENCIES: [f32; 2] = [55.0, 62.0];
+    pub const HEARTBEAT_DURATION: f32 = 0.1;
+    pub const HEARTBEAT_CUTOFF: f32 = 400.0;
+    pub const HEARTBEAT_GAIN: f32 = 0.7;
+    pub const HEARTBEAT_INTERVALS: (f32, f32) = (1.0, 0.25);
+}
+
+pub mod locale {
+    pub const DEFAULT: &str = "en";
+    pub const LOCALES: [(&str, &str, &str); 4] = [
+        ("en", "english", ""),
+        ("fr", "français", include_str!("../locales/fr.txt")),
+        ("de", "deutsch", include_str!("../locales/de.txt")),
+        ("es", "español", include_str!("../locales/es.txt")),
+    ];
+}
+
+pub mod pause {
+    pub const ITEMS: [&str; 5] = ["resume", "restart", "settings", "quit to menu", "exit"];
+    pub const BACK_LABEL: &str = "back";
+    pub const DIM_ALPHA: u8 = 160;
+}
+
+pub mod ui {
+    pub const ITEM_SPACING: u32 = 30;
+    pub const SLIDER_WIDTH: u32 = 300;
+}
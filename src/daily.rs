@@ -0,0 +1,454 @@
+use crate::constants;
+use crate::event::GameEvent;
+use crate::input::Input;
+use crate::net;
+use crate::net::bytes::Reader;
+use crate::session::Session;
+use crate::settings;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub fn get_today() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let days = (seconds / constants::daily::SECONDS_PER_DAY) as i64 + 719_468;
+
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+pub fn get_seed(date: &str) -> u64 {
+    constants::daily::SEED_PREFIX
+        .bytes()
+        .chain(date.bytes())
+        .fold(constants::session::HASH_OFFSET, |hash, b| {
+            (hash ^ b as u64).wrapping_mul(constants::session::HASH_PRIME)
+        })
+}
+
+pub fn new_session(seed: u64) -> Session {
+    let mut session = Session::new(net::field_bounds(), 1, seed);
+    if let Some(player) = session.get_player_mut(0) {
+        player.set_lives(constants::daily::LIVES);
+    }
+    session
+}
+
+pub fn get_tick_dt() -> f32 {
+    constants::net::TICK_MS as f32 / 1000.0
+}
+
+pub fn step(session: &mut Session, input: Input) -> bool {
+    let bounds = net::field_bounds();
+
+    session.set_input(0, input);
+    session.tick(get_tick_dt(), bounds);
+
+    let hits = session
+        .get_events()
+        .iter()
+        .filter(|e| matches!(e, GameEvent::PlayerHit { .. }))
+        .count();
+
+    for _ in 0..hits {
+        session.die(bounds);
+
+        let Some(player) = session.get_player_mut(0) else {
+            return true;
+        };
+
+        if player.get_lives() == 1 {
+            return true;
+        }
+
+        player.lose_life();
+    }
+
+    false
+}
+
+pub fn get_replay_path(file: &str) -> Option<PathBuf> {
+    settings::get_config_path(constants::daily::REPLAY_DIRECTORY).map(|dir| dir.join(file))
+}
+
+pub fn get_replay_file(date: &str) -> String {
+    format!("{}{}.{}", constants::daily::SEED_PREFIX, date, constants::daily::REPLAY_EXTENSION)
+}
+
+#[derive(Clone)]
+pub struct Replay {
+    date: String,
+    seed: u64,
+    score: u64,
+    hash: u64,
+    inputs: Vec<Input>,
+}
+
+impl Replay {
+    pub fn new(date: &str) -> Self {
+        Replay {
+            date: date.to_string(),
+            seed: get_seed(date),
+            score: 0,
+            hash: 0,
+            inputs: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, input: Input) {
+        self.inputs.push(input);
+    }
+
+    pub fn finish(&mut self, session: &Session) {
+        self.score = session.get_player(0).map(|p| p.get_score()).unwrap_or(0);
+        self.hash = session.hash();
+    }
+
+    pub fn get_date(&self) -> &str {
+        &self.date
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn get_score(&self) -> u64 {
+        self.score
+    }
+
+    pub fn get_hash(&self) -> u64 {
+        self.hash
+    }
+
+    pub fn get_frames(&self) -> usize {
+        self.inputs.len()
+    }
+
+    pub fn get_input(&self, frame: usize) -> Option<Input> {
+        self.inputs.get(frame).copied()
+    }
+
+    pub fn simulate(&self) -> Session {
+        let mut session = new_session(self.seed);
+
+        for &input in &self.inputs {
+            if step(&mut session, input) {
+                break;
+            }
+        }
+
+        session
+    }
+
+    pub fn verify(&self) -> Result<u64, String> {
+        if self.seed != get_seed(&self.date) {
+            return Err(String::from(constants::strings::REPLAY_SEED_ERROR));
+        }
+
+        let session = self.simulate();
+        let score = session.get_player(0).map(|p| p.get_score()).unwrap_or(0);
+
+        if score != self.score || session.hash() != self.hash {
+            return Err(format!(
+                "{}score {}, state hash {:016x}",
+                constants::strings::REPLAY_MISMATCH_ERROR,
+                score,
+                session.hash()
+            ));
+        }
+
+        Ok(score)
+    }
+
+    pub fn write(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+
+        buf.extend_from_slice(&constants::daily::REPLAY_MAGIC.to_le_bytes());
+        buf.push(constants::daily::REPLAY_VERSION);
+        buf.push(self.date.len() as u8);
+        buf.extend_from_slice(self.date.as_bytes());
+        buf.extend_from_slice(&self.seed.to_le_bytes());
+        buf.extend_from_slice(&self.score.to_le_bytes());
+        buf.extend_from_slice(&self.hash.to_le_bytes());
+        buf.extend_from_slice(&(self.inputs.len() as u32).to_le_bytes());
+        buf.extend(self.inputs.iter().map(|i| i.to_byte()));
+
+        buf
+    }
+
+    pub fn read(buf: &[u8]) -> Option<Replay> {
+        let mut reader = Reader::new(buf);
+
+        if reader.read_u16()? != constants::daily::REPLAY_MAGIC
+            || reader.read_u8()? != constants::daily::REPLAY_VERSION
+        {
+            return None;
+        }
+
+        let len = reader.read_u8()? as usize;
+        let date = String::from_utf8(reader.read_bytes(len)?.to_vec()).ok()?;
+        let seed = reader.read_u64()?;
+        let score = reader.read_u64()?;
+        let hash = reader.read_u64()?;
+
+        let frames = reader.read_u32()? as usize;
+        let inputs = reader
+            .read_bytes(frames)?
+            .iter()
+            .map(|&b| Input::from_byte(b))
+            .collect();
+
+        Some(Replay {
+            date,
+            seed,
+            score,
+            hash,
+            inputs,
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let buf = fs::read(path).map_err(|e| e.to_string())?;
+        Replay::read(&buf).ok_or(format!(
+            "{}{}",
+            constants::strings::INVALID_REPLAY_ERROR,
+            path.display()
+        ))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+
+        fs::write(path, self.write()).map_err(|e| e.to_string())
+    }
+}
+
+#[derive(Clone)]
+pub struct DailyResult {
+    pub date: String,
+    pub score: u64,
+    pub replay: String,
+    pub verified: Option<bool>,
+}
+
+pub fn verify_result(result: &DailyResult) -> bool {
+    let replay = get_replay_path(&result.replay).map(|path| Replay::load(&path));
+
+    matches!(
+        replay,
+        Some(Ok(replay)) if replay.get_date() == result.date && replay.verify() == Ok(result.score)
+    )
+}
+
+#[derive(Default)]
+pub struct Leaderboard {
+    results: Vec<DailyResult>,
+    receiver: Option<Receiver<(String, bool)>>,
+}
+
+impl Leaderboard {
+    pub fn load() -> Result<Self, String> {
+        let mut leaderboard = Leaderboard::default();
+
+        let Some(path) = settings::get_config_path(constants::daily::FILE) else {
+            return Ok(leaderboard);
+        };
+
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(leaderboard),
+            Err(e) => return Err(e.to_string()),
+        };
+
+        leaderboard.results = contents
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let invalid = || format!("{}{}", constants::strings::INVALID_VALUE_ERROR, line);
+
+                let result = line.split_once('=').ok_or_else(invalid).and_then(|(date, value)| {
+                    let (score, replay) = value.trim().split_once(' ').ok_or_else(invalid)?;
+
+                    Ok(DailyResult {
+                        date: date.trim().to_string(),
+                        score: score.parse().map_err(|_| invalid())?,
+                        replay: replay.trim().to_string(),
+                        verified: None,
+                    })
+                });
+
+                result
+                    .map_err(|e| println!("{}{}", constants::strings::DAILY_ERROR, e))
+                    .ok()
+            })
+            .collect();
+
+        Ok(leaderboard)
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = settings::get_config_path(constants::daily::FILE)
+            .ok_or(String::from(constants::strings::CONFIG_DIR_ERROR))?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+
+        let contents = self
+            .results
+            .iter()
+            .map(|r| format!("{}={} {}\n", r.date, r.score, r.replay))
+            .collect::<String>();
+
+        fs::write(path, contents).map_err(|e| e.to_string())
+    }
+
+    pub fn has_played(&self, date: &str) -> bool {
+        self.results.iter().any(|r| r.date == date)
+    }
+
+    pub fn get_result(&self, date: &str) -> Option<&DailyResult> {
+        self.results.iter().find(|r| r.date == date)
+    }
+
+    pub fn record(&mut self, date: &str, score: u64, verified: Option<bool>) {
+        self.results.retain(|r| r.date != date);
+        self.results.push(DailyResult {
+            date: date.to_string(),
+            score,
+            replay: get_replay_file(date),
+            verified,
+        });
+    }
+
+    pub fn verify(&mut self) {
+        let pending = self
+            .results
+            .iter()
+            .filter(|r| r.verified.is_none())
+            .cloned()
+            .collect::<Vec<DailyResult>>();
+
+        if pending.is_empty() || self.receiver.is_some() {
+            return;
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            pending
+                .iter()
+                .map(|r| (r.date.clone(), verify_result(r)))
+                .try_for_each(|result| sender.send(result))
+        });
+
+        self.receiver = Some(receiver);
+    }
+
+    pub fn poll(&mut self) {
+        let Some(receiver) = &self.receiver else {
+            return;
+        };
+
+        loop {
+            match receiver.try_recv() {
+                Ok((date, verified)) => {
+                    if let Some(result) = self.results.iter_mut().find(|r| r.date == date) {
+                        result.verified = Some(verified);
+                    }
+                }
+                Err(TryRecvError::Empty) => return,
+                Err(TryRecvError::Disconnected) => break,
+            }
+        }
+
+        self.receiver = None;
+        self.verify();
+    }
+
+    pub fn get_top(&self, count: usize) -> Vec<&DailyResult> {
+        let mut results = self.results.iter().collect::<Vec<&DailyResult>>();
+        results.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| b.date.cmp(&a.date)));
+        results.truncate(count);
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::autopilot::Autopilot;
+
+    const DATE: &str = "2026-01-01";
+
+    fn record(frames: usize) -> Replay {
+        let mut replay = Replay::new(DATE);
+        let mut session = new_session(replay.get_seed());
+        let mut autopilot = Autopilot::new(0);
+
+        for _ in 0..frames {
+            let input = autopilot.get_input(&session, net::field_bounds());
+            replay.record(input);
+
+            if step(&mut session, input) {
+                break;
+            }
+        }
+
+        replay.finish(&session);
+        replay
+    }
+
+    #[test]
+    fn recorded_replay_verifies() {
+        let replay = record(3600);
+
+        assert!(replay.get_score() > 0);
+        assert_eq!(replay.verify(), Ok(replay.get_score()));
+    }
+
+    #[test]
+    fn tampered_replay_fails() {
+        let mut replay = record(600);
+        replay.score += 1;
+
+        assert!(replay.verify().is_err());
+    }
+
+    #[test]
+    fn write_read_round_trip() {
+        let replay = record(600);
+        let read = Replay::read(&replay.write()).unwrap();
+
+        assert_eq!(read.get_date(), replay.get_date());
+        assert_eq!(read.get_seed(), replay.get_seed());
+        assert_eq!(read.get_score(), replay.get_score());
+        assert_eq!(read.get_hash(), replay.get_hash());
+        assert!(read.inputs == replay.inputs);
+        assert_eq!(read.verify(), Ok(replay.get_score()));
+    }
+
+    #[test]
+    fn read_rejects_truncated() {
+        let buf = record(600).write();
+
+        assert!(Replay::read(&buf[..buf.len() - 1]).is_none());
+    }
+}
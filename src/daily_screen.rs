@@ -0,0 +1,153 @@
+use crate::constants;
+use crate::daily::Leaderboard;
+use crate::input::MenuInput;
+use crate::locale::Locale;
+use crate::ui::{self, Align, Menu, MenuEvent, Widget};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::{BlendMode, Canvas};
+use sdl2::video::Window;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum DailyAction {
+    Play,
+    Close,
+}
+
+pub struct DailyScreen {
+    open: bool,
+    today: String,
+    played: bool,
+    menu: Menu,
+    scale: f32,
+}
+
+impl Default for DailyScreen {
+    fn default() -> Self {
+        DailyScreen {
+            open: false,
+            today: String::new(),
+            played: false,
+            menu: Menu::new("", Vec::new()),
+            scale: 1.0,
+        }
+    }
+}
+
+impl DailyScreen {
+    pub fn new() -> Self {
+        DailyScreen::default()
+    }
+
+    pub fn open(&mut self, leaderboard: &mut Leaderboard, today: &str) {
+        leaderboard.verify();
+
+        self.open = true;
+        self.today = today.to_string();
+        self.played = leaderboard.has_played(today);
+
+        let items = if self.played {
+            vec![constants::pause::BACK_LABEL]
+        } else {
+            vec![constants::daily::PLAY_LABEL, constants::pause::BACK_LABEL]
+        };
+        self.menu = Menu::new("", items.iter().map(|s| Widget::Button(s.to_string())).collect());
+        self.menu.set_scale(self.scale);
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale;
+        self.menu.set_scale(scale);
+    }
+
+    pub fn handle_input(&mut self, input: MenuInput) -> Option<DailyAction> {
+        let action = match self.menu.handle_input(input)? {
+            MenuEvent::Activated(0) if !self.played => DailyAction::Play,
+            MenuEvent::Activated(_) | MenuEvent::Back => DailyAction::Close,
+            MenuEvent::Changed(_) => return None,
+        };
+
+        self.close();
+
+        Some(action)
+    }
+
+    pub fn render(
+        &self,
+        canvas: &mut Canvas<Window>,
+        screen_bounds: Rect,
+        locale: &Locale,
+        leaderboard: &Leaderboard,
+    ) -> Result<(), String> {
+        if !self.open {
+            return Ok(());
+        }
+
+        canvas.set_blend_mode(BlendMode::Blend);
+        canvas.set_draw_color(Color::RGBA(0, 0, 0, constants::pause::DIM_ALPHA));
+        canvas.fill_rect(screen_bounds)?;
+        canvas.set_blend_mode(BlendMode::None);
+
+        let center = screen_bounds.x() + screen_bounds.width() as i32 / 2;
+        let mut y = screen_bounds.y() + screen_bounds.height() as i32 / constants::stats::TOP_MARGIN_DIVISOR;
+
+        let title = locale.translate(constants::strings::DAILY_TEXT);
+        ui::render_text(title, center, y, Align::Center, self.scale, canvas)?;
+        y += ui::measure_text(title, self.scale).1 as i32 * 2;
+
+        let scale = self.scale * constants::stats::TABLE_SCALE;
+        let padding = (constants::font::MARGIN as f32 * scale) as i32;
+
+        let today = match leaderboard.get_result(&self.today) {
+            Some(result) => format!(
+                "{} {}: {}\n{}",
+                locale.translate(constants::daily::TODAY_TEXT),
+                self.today,
+                result.score,
+                locale.translate(constants::daily::PLAYED_TEXT)
+            ),
+            None => format!("{} {}", locale.translate(constants::daily::TODAY_TEXT), self.today),
+        };
+        ui::render_text(&today, center, y, Align::Center, scale, canvas)?;
+        y += ui::measure_text(&today, scale).1 as i32 + ui::measure_text("", scale).1 as i32;
+
+        let results = leaderboard.get_top(constants::daily::LEADERBOARD_SIZE);
+
+        let dates = results
+            .iter()
+            .enumerate()
+            .map(|(i, r)| format!("{}. {}", i + 1, r.date))
+            .collect::<Vec<String>>()
+            .join("\n");
+        let scores = results
+            .iter()
+            .map(|r| {
+                let icon = match r.verified {
+                    Some(true) => constants::font::CHECK_ICON.to_string(),
+                    Some(false) => constants::font::CROSS_ICON.to_string(),
+                    None => String::from(constants::daily::PENDING_TEXT),
+                };
+                format!("{} {} {}", r.score, icon, r.replay)
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        ui::render_text(&dates, center - padding, y, Align::Right, scale, canvas)?;
+        ui::render_text(&scores, center + padding, y, Align::Left, scale, canvas)?;
+
+        let menu_top = screen_bounds.y() + screen_bounds.height() as i32 * 3 / 4;
+        self.menu.render(
+            canvas,
+            Rect::new(screen_bounds.x(), menu_top, screen_bounds.width(), screen_bounds.height() / 4),
+            locale,
+        )
+    }
+}
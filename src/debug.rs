@@ -0,0 +1,191 @@
+use crate::constants;
+use crate::font;
+use crate::session::Session;
+use sdl2::rect::{Point, Rect};
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+use std::collections::VecDeque;
+use std::f32::consts::PI;
+
+#[derive(Default)]
+pub struct DebugOverlay {
+    enabled: bool,
+    frame_times: VecDeque<f32>,
+}
+
+impl DebugOverlay {
+    pub fn new() -> Self {
+        DebugOverlay {
+            enabled: false,
+            frame_times: VecDeque::new(),
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        self.enabled = !self.enabled;
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn record_frame(&mut self, dt: f32) {
+        self.frame_times.push_back(dt);
+
+        while self.frame_times.len() > constants::debug::FRAME_HISTORY {
+            self.frame_times.pop_front();
+        }
+    }
+
+    pub fn render_world(
+        &self,
+        canvas: &mut Canvas<Window>,
+        session: &Session,
+        screen_bounds: Rect,
+        dt: f32,
+    ) -> Result<(), String> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        canvas.set_draw_color(constants::debug::HITBOX_COLOR);
+
+        session
+            .get_players()
+            .iter()
+            .try_for_each(|p| draw_polygon(canvas, &p.get_hitbox()))?;
+
+        session.get_asteroids().iter().try_for_each(|a| {
+            a.get_hitboxes(screen_bounds)
+                .iter()
+                .try_for_each(|h| draw_polygon(canvas, h))
+        })?;
+
+        session.get_aliens().iter().try_for_each(|a| {
+            a.get_hitboxes(screen_bounds)
+                .iter()
+                .try_for_each(|h| draw_polygon(canvas, h))
+        })?;
+
+        canvas.set_draw_color(constants::debug::TRAIL_COLOR);
+
+        session.get_bullets().iter().try_for_each(|b| {
+            let trail = b.get_physics_trail(dt);
+            draw_line(canvas, trail[0], trail[1])
+        })?;
+
+        canvas.set_draw_color(constants::debug::RANGE_COLOR);
+
+        session.get_black_holes().iter().try_for_each(|b| {
+            let range = session.get_tuning().black_hole_range * b.get_radius();
+            let points = (0..constants::debug::CIRCLE_SEGMENTS)
+                .map(|i| {
+                    let angle = i as f32 * 2.0 * PI / constants::debug::CIRCLE_SEGMENTS as f32;
+                    (b.get_x() + range * angle.cos(), b.get_y() + range * angle.sin())
+                })
+                .collect::<Vec<(f32, f32)>>();
+
+            draw_polygon(canvas, &points)
+        })?;
+
+        canvas.set_draw_color(constants::debug::VELOCITY_COLOR);
+
+        let mut velocities: Vec<(f32, f32, f32, f32)> = Vec::new();
+        session
+            .get_players()
+            .iter()
+            .for_each(|p| velocities.push(p.get_pos_and_vel()));
+        session.get_asteroids().iter().for_each(|a| {
+            let (vx, vy) = a.get_velocity();
+            velocities.push((a.get_x(), a.get_y(), vx, vy));
+        });
+        session.get_aliens().iter().for_each(|a| {
+            let (vx, vy) = a.get_velocity();
+            velocities.push((a.get_x(), a.get_y(), vx, vy));
+        });
+
+        velocities.iter().try_for_each(|&(x, y, vx, vy)| {
+            draw_line(
+                canvas,
+                (x, y),
+                (
+                    x + vx * constants::debug::VELOCITY_SCALE,
+                    y + vy * constants::debug::VELOCITY_SCALE,
+                ),
+            )
+        })
+    }
+
+    pub fn render_hud(&self, canvas: &mut Canvas<Window>, session: &Session, screen_bounds: Rect) -> Result<(), String> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let average = self.frame_times.iter().sum::<f32>() / self.frame_times.len().max(1) as f32;
+        let fps = if average > 0.0 { (1.0 / average).round() as u32 } else { 0 };
+
+        let lines = [
+            (constants::strings::DEBUG_ASTEROIDS, session.get_asteroids().len() as u32),
+            (constants::strings::DEBUG_ALIENS, session.get_aliens().len() as u32),
+            (constants::strings::DEBUG_BULLETS, session.get_bullets().len() as u32),
+            (constants::strings::DEBUG_BLACK_HOLES, session.get_black_holes().len() as u32),
+            (constants::strings::DEBUG_PARTICLES, session.get_particles().len() as u32),
+            (constants::strings::DEBUG_FPS, fps),
+        ];
+
+        let line_height = (2 * constants::font::FONT_SIZE + constants::font::MARGIN) as i32;
+
+        lines.iter().enumerate().try_for_each(|(i, (label, count))| {
+            font::render_text(
+                format!("{} {}", label, count).as_str(),
+                10,
+                constants::debug::TEXT_Y + i as i32 * line_height,
+                canvas,
+            )
+        })?;
+
+        self.render_frame_graph(canvas, screen_bounds)
+    }
+
+    fn render_frame_graph(&self, canvas: &mut Canvas<Window>, screen_bounds: Rect) -> Result<(), String> {
+        let bottom = screen_bounds.height() as i32 - 10;
+        let left = screen_bounds.width() as i32
+            - 10
+            - (constants::debug::FRAME_HISTORY as u32 * constants::debug::GRAPH_BAR_WIDTH) as i32;
+
+        canvas.set_draw_color(constants::debug::GRAPH_COLOR);
+
+        self.frame_times.iter().enumerate().try_for_each(|(i, dt)| {
+            let height = (dt * 1000.0 * constants::debug::GRAPH_SCALE).max(1.0) as u32;
+
+            canvas.fill_rect(Rect::new(
+                left + (i as u32 * constants::debug::GRAPH_BAR_WIDTH) as i32,
+                bottom - height as i32,
+                constants::debug::GRAPH_BAR_WIDTH,
+                height,
+            ))
+        })?;
+
+        canvas.set_draw_color(constants::debug::TARGET_COLOR);
+
+        let target = bottom - (constants::debug::TARGET_FRAME_MS * constants::debug::GRAPH_SCALE) as i32;
+        canvas.draw_line(
+            Point::new(left, target),
+            Point::new(screen_bounds.width() as i32 - 10, target),
+        )
+    }
+}
+
+fn draw_line(canvas: &mut Canvas<Window>, a: (f32, f32), b: (f32, f32)) -> Result<(), String> {
+    canvas.draw_line(
+        Point::new(a.0 as i32, a.1 as i32),
+        Point::new(b.0 as i32, b.1 as i32),
+    )
+}
+
+fn draw_polygon(canvas: &mut Canvas<Window>, points: &[(f32, f32)]) -> Result<(), String> {
+    points
+        .iter()
+        .enumerate()
+        .try_for_each(|(i, &a)| draw_line(canvas, a, points[(i + 1) % points.len()]))
+}
@@ -0,0 +1,385 @@
+use crate::alien::ShootingType;
+use crate::constants;
+use crate::input::Input;
+use crate::net;
+use crate::session::Session;
+use sdl2::rect::Rect;
+
+pub type Action = Input;
+
+#[derive(Clone, Copy, Default)]
+pub struct EntityObservation {
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+    pub radius: f32,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct PlayerObservation {
+    pub entity: EntityObservation,
+    pub angle: f32,
+    pub lives: u32,
+    pub score: u64,
+}
+
+#[derive(Clone, Default)]
+pub struct Observation {
+    pub player: PlayerObservation,
+    pub asteroids: Vec<EntityObservation>,
+    pub aliens: Vec<(EntityObservation, ShootingType)>,
+    pub bullets: Vec<(EntityObservation, bool)>,
+    pub black_holes: Vec<EntityObservation>,
+    pub frame: Option<Vec<u8>>,
+    pub frame_size: Option<(u32, u32)>,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct Info {
+    pub score: u64,
+    pub points: u64,
+    pub lives: u32,
+    pub lives_lost: u32,
+    pub extra_lives: u32,
+    pub steps: u64,
+    pub time: u64,
+}
+
+#[derive(Clone, Copy)]
+pub struct RewardShaping {
+    pub per_point: f32,
+    pub life_lost: f32,
+    pub extra_life: f32,
+    pub per_step: f32,
+}
+
+impl Default for RewardShaping {
+    fn default() -> Self {
+        RewardShaping {
+            per_point: constants::env::POINT_REWARD,
+            life_lost: constants::env::LIFE_LOST_REWARD,
+            extra_life: constants::env::EXTRA_LIFE_REWARD,
+            per_step: constants::env::STEP_REWARD,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct EnvConfig {
+    pub frame_skip: u32,
+    pub raster: Option<(u32, u32)>,
+    pub max_steps: Option<u64>,
+    pub shaping: RewardShaping,
+}
+
+impl Default for EnvConfig {
+    fn default() -> Self {
+        EnvConfig {
+            frame_skip: constants::env::DEFAULT_FRAME_SKIP,
+            raster: None,
+            max_steps: None,
+            shaping: RewardShaping::default(),
+        }
+    }
+}
+
+type RewardHook = Box<dyn FnMut(&Session, &Info) -> f32>;
+
+pub struct Env {
+    config: EnvConfig,
+    session: Session,
+    field_bounds: Rect,
+
+    steps: u64,
+    done: bool,
+
+    reward_hook: Option<RewardHook>,
+}
+
+impl Env {
+    pub fn new(config: EnvConfig) -> Self {
+        let field_bounds = net::field_bounds();
+
+        Env {
+            config,
+            session: Session::new(field_bounds, 1, 0),
+            field_bounds,
+
+            steps: 0,
+            done: false,
+
+            reward_hook: None,
+        }
+    }
+
+    pub fn reset(&mut self, seed: u64) -> Observation {
+        self.session = Session::new(self.field_bounds, 1, seed);
+        self.steps = 0;
+        self.done = false;
+
+        self.get_observation()
+    }
+
+    pub fn step(&mut self, action: Action) -> (Observation, f32, bool, Info) {
+        let dt = constants::net::TICK_MS as f32 / 1000.0;
+
+        let start_score = self.get_score_and_lives().0;
+        let mut info = Info::default();
+        let mut game_over = false;
+
+        if !self.done {
+            self.session.set_input(0, action);
+
+            for _ in 0..self.config.frame_skip.max(1) {
+                let lives = self.get_score_and_lives().1;
+                let hit = !self.session.tick(dt, self.field_bounds).is_empty();
+                info.extra_lives += self.get_score_and_lives().1.saturating_sub(lives);
+
+                if hit {
+                    info.lives_lost += 1;
+                    self.session.die(self.field_bounds);
+
+                    match self.session.get_player_mut(0) {
+                        Some(p) if p.get_lives() > 1 => p.lose_life(),
+                        _ => {
+                            game_over = true;
+                            break;
+                        }
+                    }
+                }
+            }
+
+            self.steps += 1;
+        }
+
+        self.done |= game_over || self.config.max_steps.is_some_and(|max| self.steps >= max);
+
+        let (score, lives) = self.get_score_and_lives();
+        info.score = score;
+        info.points = score - start_score;
+        info.lives = if game_over { 0 } else { lives };
+        info.steps = self.steps;
+        info.time = self.session.get_time();
+
+        let shaping = self.config.shaping;
+        let mut reward = info.points as f32 * shaping.per_point
+            + info.lives_lost as f32 * shaping.life_lost
+            + info.extra_lives as f32 * shaping.extra_life
+            + shaping.per_step;
+
+        if let Some(hook) = &mut self.reward_hook {
+            reward += hook(&self.session, &info);
+        }
+
+        (self.get_observation(), reward, self.done, info)
+    }
+
+    pub fn set_reward_hook(&mut self, hook: RewardHook) {
+        self.reward_hook = Some(hook);
+    }
+
+    pub fn set_shaping(&mut self, shaping: RewardShaping) {
+        self.config.shaping = shaping;
+    }
+
+    pub fn set_frame_skip(&mut self, frame_skip: u32) {
+        self.config.frame_skip = frame_skip;
+    }
+
+    pub fn set_raster(&mut self, raster: Option<(u32, u32)>) {
+        self.config.raster = raster;
+    }
+
+    pub fn get_session(&self) -> &Session {
+        &self.session
+    }
+
+    pub fn get_field_bounds(&self) -> Rect {
+        self.field_bounds
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    fn get_score_and_lives(&self) -> (u64, u32) {
+        self.session
+            .get_player(0)
+            .map_or((0, 0), |p| (p.get_score(), p.get_lives()))
+    }
+
+    pub fn get_observation(&self) -> Observation {
+        let player = self
+            .session
+            .get_player(0)
+            .map_or(PlayerObservation::default(), |p| {
+                let (x, y, vx, vy) = p.get_pos_and_vel();
+
+                PlayerObservation {
+                    entity: EntityObservation {
+                        x,
+                        y,
+                        vx,
+                        vy,
+                        radius: constants::player::RADIUS,
+                    },
+                    angle: p.get_angle(),
+                    lives: p.get_lives(),
+                    score: p.get_score(),
+                }
+            });
+
+        let asteroids = self
+            .session
+            .get_asteroids()
+            .iter()
+            .map(|a| {
+                let (vx, vy) = a.get_velocity();
+                EntityObservation {
+                    x: a.get_x(),
+                    y: a.get_y(),
+                    vx,
+                    vy,
+                    radius: a.get_radius(),
+                }
+            })
+            .collect();
+
+        let aliens = self
+            .session
+            .get_aliens()
+            .iter()
+            .map(|a| {
+                let (vx, vy) = a.get_velocity();
+                let entity = EntityObservation {
+                    x: a.get_x(),
+                    y: a.get_y(),
+                    vx,
+                    vy,
+                    radius: constants::alien::RADIUS,
+                };
+                (entity, a.get_type())
+            })
+            .collect();
+
+        let bullets = self
+            .session
+            .get_bullets()
+            .iter()
+            .map(|b| {
+                let (x, y) = b.get_location();
+                let (vx, vy) = b.get_velocity();
+                let entity = EntityObservation {
+                    x,
+                    y,
+                    vx,
+                    vy,
+                    radius: 0.0,
+                };
+                (entity, b.get_is_player_shot())
+            })
+            .collect();
+
+        let black_holes = self
+            .session
+            .get_black_holes()
+            .iter()
+            .map(|b| EntityObservation {
+                x: b.get_x(),
+                y: b.get_y(),
+                vx: 0.0,
+                vy: 0.0,
+                radius: b.get_radius(),
+            })
+            .collect();
+
+        Observation {
+            player,
+            asteroids,
+            aliens,
+            bullets,
+            black_holes,
+            frame: self.config.raster.map(|(w, h)| self.rasterise(w, h)),
+            frame_size: self.config.raster,
+        }
+    }
+
+    pub fn rasterise(&self, width: u32, height: u32) -> Vec<u8> {
+        let mut frame = vec![0_u8; (width * height) as usize];
+        let sx = width as f32 / self.field_bounds.width() as f32;
+        let sy = height as f32 / self.field_bounds.height() as f32;
+
+        let mut draw_polygon = |points: &[(f32, f32)]| {
+            points.iter().enumerate().for_each(|(i, &a)| {
+                let b = points[(i + 1) % points.len()];
+                draw_line(
+                    &mut frame,
+                    width,
+                    height,
+                    (a.0 * sx, a.1 * sy),
+                    (b.0 * sx, b.1 * sy),
+                );
+            });
+        };
+
+        self.session
+            .get_players()
+            .iter()
+            .for_each(|p| draw_polygon(&p.get_hitbox()));
+
+        self.session.get_asteroids().iter().for_each(|a| {
+            a.get_hitboxes(self.field_bounds)
+                .iter()
+                .for_each(|h| draw_polygon(h))
+        });
+
+        self.session.get_aliens().iter().for_each(|a| {
+            a.get_hitboxes(self.field_bounds)
+                .iter()
+                .for_each(|h| draw_polygon(h))
+        });
+
+        self.session.get_black_holes().iter().for_each(|b| {
+            let points = (0..constants::env::CIRCLE_SEGMENTS)
+                .map(|i| {
+                    let angle = i as f32 * 2.0 * std::f32::consts::PI
+                        / constants::env::CIRCLE_SEGMENTS as f32;
+                    (
+                        b.get_x() + b.get_radius() * angle.cos(),
+                        b.get_y() + b.get_radius() * angle.sin(),
+                    )
+                })
+                .collect::<Vec<(f32, f32)>>();
+            draw_polygon(&points);
+        });
+
+        self.session.get_bullets().iter().for_each(|b| {
+            let (x, y) = b.get_location();
+            plot(&mut frame, width, height, (x * sx) as i32, (y * sy) as i32);
+        });
+
+        frame
+    }
+}
+
+fn plot(frame: &mut [u8], width: u32, height: u32, x: i32, y: i32) {
+    if x >= 0 && y >= 0 && (x as u32) < width && (y as u32) < height {
+        frame[(y as u32 * width + x as u32) as usize] = u8::MAX;
+    }
+}
+
+fn draw_line(frame: &mut [u8], width: u32, height: u32, a: (f32, f32), b: (f32, f32)) {
+    let steps = (b.0 - a.0).abs().max((b.1 - a.1).abs()).ceil().max(1.0) as i32;
+
+    (0..=steps).for_each(|i| {
+        let t = i as f32 / steps as f32;
+        plot(
+            frame,
+            width,
+            height,
+            (a.0 + (b.0 - a.0) * t) as i32,
+            (a.1 + (b.1 - a.1) * t) as i32,
+        );
+    });
+}
@@ -0,0 +1,40 @@
+use crate::alien::ShootingType;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum HitCause {
+    Asteroid,
+    AlienBullet,
+    PlayerBullet(usize),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum GameEvent {
+    AsteroidDestroyed {
+        radius: f32,
+        pos: (f32, f32),
+        by: Option<usize>,
+    },
+    AlienKilled {
+        shooting_type: ShootingType,
+        pos: (f32, f32),
+        by: Option<usize>,
+    },
+    PlayerHit {
+        player: usize,
+        cause: HitCause,
+        assisted: bool,
+        pos: (f32, f32),
+    },
+    ExtraLife {
+        player: usize,
+    },
+    WaveCleared,
+    BulletFired {
+        owner: Option<usize>,
+        pos: (f32, f32),
+    },
+}
+
+pub trait Subscriber {
+    fn handle_event(&mut self, event: &GameEvent);
+}
@@ -6,46 +6,72 @@ use sdl2::video::Window;
 
 pub fn render_text(
     text: &str,
-    mut x: i32,
+    x: i32,
     y: i32,
     canvas: &mut Canvas<Window>,
 ) -> Result<(), String> {
-    canvas.set_draw_color(Color::RGB(255, 255, 255));
-
-    text.as_bytes().iter().try_for_each(|&c| {
-        if c >= '0' as u8 && c <= '9' as u8 {
-            let idx = c as u32 - '0' as u32;
-
-            render_path(constants::font::NUMBERS[idx as usize], x, y, canvas)?;
-
-        } else if c >= 'a' as u8 && c <= 'z' as u8 {
-            let idx = c as u32 - 'a' as u32;
-
-            render_path(constants::font::LETTERS[idx as usize], x, y, canvas)?;
-        }
+    render_text_styled(text, x, y, 1.0, Color::RGB(255, 255, 255), canvas)
+}
 
-        x += (constants::font::FONT_SIZE + constants::font::MARGIN) as i32;
-        Ok::<(), String>(())
-    })?;
+pub fn render_text_styled(
+    text: &str,
+    x: i32,
+    y: i32,
+    scale: f32,
+    color: Color,
+    canvas: &mut Canvas<Window>,
+) -> Result<(), String> {
+    let size = constants::font::FONT_SIZE as f32 * scale;
+    let advance = (constants::font::FONT_SIZE + constants::font::MARGIN) as f32 * scale;
+    let line_height = (2 * constants::font::FONT_SIZE + constants::font::LINE_SPACING) as f32 * scale;
+
+    canvas.set_draw_color(color);
+
+    text.lines().enumerate().try_for_each(|(row, line)| {
+        let y = y + (row as f32 * line_height) as i32;
+
+        line.chars().enumerate().try_for_each(|(i, c)| {
+            let x = x + (i as f32 * advance) as i32;
+
+            match get_glyph(c) {
+                Some((path, accent)) => {
+                    render_path(path, x, y, size, canvas)?;
+                    accent.map_or(Ok(()), |accent| render_path(accent, x, y, size, canvas))
+                }
+                None => Ok(()),
+            }
+        })
+    })
+}
 
-    Ok(())
+pub fn get_glyph(c: char) -> Option<(constants::font::Glyph, Option<constants::font::Glyph>)> {
+    match c {
+        '0'..='9' => Some((constants::font::NUMBERS[c as usize - '0' as usize], None)),
+        'a'..='z' => Some((constants::font::LETTERS[c as usize - 'a' as usize], None)),
+        'A'..='Z' => Some((constants::font::LETTERS[c as usize - 'A' as usize], None)),
+        _ => constants::font::SYMBOLS
+            .iter()
+            .find(|(symbol, _)| *symbol == c)
+            .map(|(_, path)| (*path, None))
+            .or_else(|| {
+                constants::font::ACCENTED.iter().find_map(|(accented, bases, accent)| {
+                    let base = bases.chars().nth(accented.chars().position(|a| a == c)?)?;
+                    get_glyph(base).map(|(path, _)| (path, Some(*accent)))
+                })
+            }),
+    }
 }
 
 fn render_path(
     path: &[[(f32, f32); 2]],
     x: i32,
     y: i32,
+    size: f32,
     canvas: &mut Canvas<Window>,
 ) -> Result<(), String> {
     path.iter().try_for_each(|l| {
-        let p1 = Point::new(
-            (l[0].0 * constants::font::FONT_SIZE as f32) as i32 + x,
-            (l[0].1 * constants::font::FONT_SIZE as f32) as i32 + y,
-        );
-        let p2 = Point::new(
-            (l[1].0 * constants::font::FONT_SIZE as f32) as i32 + x,
-            (l[1].1 * constants::font::FONT_SIZE as f32) as i32 + y,
-        );
+        let p1 = Point::new((l[0].0 * size) as i32 + x, (l[0].1 * size) as i32 + y);
+        let p2 = Point::new((l[1].0 * size) as i32 + x, (l[1].1 * size) as i32 + y);
 
         canvas.draw_line(p1, p2)?;
 
@@ -1,55 +1,121 @@
-use crate::alien::{Alien, ShootingType};
+use crate::achievements::Achievements;
+use crate::achievements_screen::AchievementsScreen;
+use crate::alien::Alien;
 use crate::asteroid::Asteroid;
+use crate::attract::{self, Attract};
+use crate::audio::{Audio, NullBackend, SdlBackend};
+use crate::autopilot::Autopilot;
 use crate::black_hole::BlackHole;
-use crate::bullet::Bullet;
+use crate::console::{Command, Console};
 use crate::constants;
+use crate::daily::{self, Leaderboard, Replay};
+use crate::daily_screen::{DailyAction, DailyScreen};
+use crate::debug::DebugOverlay;
+use crate::event::{GameEvent, Subscriber};
 use crate::font;
 use crate::high_score;
-use crate::particle::Particle;
-use crate::player::Player;
-use crate::polygon;
-use crate::polygon::point_intersects_polygon;
+use crate::input::{Input, MenuInput};
+use crate::leaderboard_screen::LeaderboardScreen;
+use crate::locale::Locale;
+use crate::mode::{Leaderboards, Mode};
+use crate::net;
+use crate::net::broadcaster::Broadcaster;
+use crate::net::client::Client;
+use crate::net::rollback::RollbackPeer;
+use crate::net::spectator::Spectator;
+use crate::net::transport::Transport;
+use crate::pause::{PauseAction, PauseMenu};
+use crate::sandbox::{Hazards, Sandbox, SandboxAction, SandboxMenu};
+use crate::session::Session;
+use crate::settings::Settings;
+use crate::settings_menu::{SettingsEvent, SettingsMenu};
+use crate::stats::Stats;
+use crate::stats_screen::{StatsAction, StatsScreen};
+use crate::tutorial::Tutorial;
+use crate::ui::{self, Align, Menu, MenuEvent, Widget};
 use rand::Rng;
-use sdl2::event::Event;
+use sdl2::GameControllerSubsystem;
+use sdl2::controller::{Button, GameController};
+use sdl2::event::{Event, WindowEvent};
 use sdl2::keyboard::Keycode;
 use sdl2::pixels::Color;
 use sdl2::rect::Rect;
 use sdl2::render::Canvas;
-use sdl2::sys::SDL_GetTicks64;
-use sdl2::video::Window;
+use sdl2::sys::{SDL_GetTicks64, SDL_RenderSetVSync};
+use sdl2::video::{FullscreenType, Window};
+use std::iter;
 
 enum GameState {
     MainMenu,
+    PlayerReady,
     InGame,
+    GameOver,
+    Online,
+    PeerToPeer,
+    Spectating,
 }
 
 pub struct Game {
     canvas: Canvas<Window>,
     event_pump: sdl2::EventPump,
 
-    screen_bounds: Rect,
+    controller_subsystem: GameControllerSubsystem,
+    controllers: Vec<GameController>,
 
-    space_released: bool,
-    next_asteroid_spawn: u64,
-    next_alien_spawn: u64,
-    next_black_hole_spawn: u64,
+    screen_bounds: Rect,
 
-    lives: u32,
-    next_life_points: u64,
+    input: Input,
 
-    score: u64,
     pb: u64,
 
-    player: Player,
+    sessions: Vec<Session>,
+    current_session: usize,
+
+    demo: Session,
+    autopilot: Autopilot,
+    attract: Attract,
+
+    client: Option<Client>,
+    peer: Option<RollbackPeer>,
+    spectator: Option<Spectator>,
+    broadcaster: Option<Broadcaster>,
+
+    debug: DebugOverlay,
+    frame_dt: f32,
+
+    console: Console,
+    god: bool,
+    unranked: bool,
+    time_scale: f32,
+
+    settings: Settings,
+    locale: Locale,
+    audio: Audio,
 
-    particles: Vec<Particle>,
-    bullets: Vec<Bullet>,
-    asteroids: Vec<Asteroid>,
-    aliens: Vec<Alien>,
-    black_holes: Vec<BlackHole>,
+    stats: Vec<Stats>,
+    lifetime_stats: Stats,
+    achievements: Achievements,
+
+    mode: Mode,
+    leaderboards: Leaderboards,
+    sandbox: Option<Sandbox>,
+    tutorial: Option<Tutorial>,
+
+    leaderboard: Leaderboard,
+    replay: Option<Replay>,
+    daily: bool,
+    accumulator: f32,
 
     state: GameState,
-    paused: bool,
+    main_menu: Menu,
+    pause_menu: PauseMenu,
+    settings_menu: SettingsMenu,
+    stats_screen: StatsScreen,
+    achievements_screen: AchievementsScreen,
+    daily_screen: DailyScreen,
+    leaderboard_screen: LeaderboardScreen,
+    sandbox_menu: SandboxMenu,
+    quit: bool,
 }
 
 impl Game {
@@ -57,72 +123,151 @@ impl Game {
         let sdl_context = sdl2::init()?;
         let video_subsystem = sdl_context.video()?;
 
-        let window = video_subsystem
-            .window(constants::window::TITLE, 0, 0)
-            .position_centered()
-            .fullscreen()
-            .build()
-            .map_err(|e| e.to_string())?;
+        let settings = Settings::load().unwrap_or_else(|e| {
+            println!("{}{}", constants::strings::SETTINGS_ERROR, e);
+            Settings::default()
+        });
 
-        let display_index = window.display_index()?;
-        let screen_bounds = video_subsystem.display_bounds(display_index)?;
+        let (width, height) = settings.resolution;
+        let mut window_builder = video_subsystem.window(constants::window::TITLE, width, height);
+        window_builder.position_centered();
+        if settings.fullscreen {
+            window_builder.fullscreen_desktop();
+        }
 
-        let canvas = window
-            .into_canvas()
-            .present_vsync()
-            .accelerated()
-            .build()
-            .map_err(|e| e.to_string())?;
+        let window = window_builder.build().map_err(|e| e.to_string())?;
+
+        let (width, height) = window.drawable_size();
+        let screen_bounds = Rect::new(0, 0, width, height);
+
+        let mut canvas_builder = window.into_canvas().accelerated();
+        if settings.vsync {
+            canvas_builder = canvas_builder.present_vsync();
+        }
+
+        let canvas = canvas_builder.build().map_err(|e| e.to_string())?;
 
         let event_pump = sdl_context.event_pump()?;
+        let controller_subsystem = sdl_context.game_controller()?;
 
-        let p = Player::new(
-            (screen_bounds.width() / 2) as f32,
-            (screen_bounds.height() / 2) as f32,
-        );
+        let audio = match sdl_context.audio().and_then(|a| SdlBackend::new(&a)) {
+            Ok(backend) => Audio::new(Box::new(backend)),
+            Err(e) => {
+                println!("{}{}", constants::strings::AUDIO_ERROR, e);
+                Audio::new(Box::new(NullBackend::new()))
+            }
+        };
 
         let pb = high_score::load_score().unwrap_or(0);
 
-        Ok(Self {
+        let lifetime_stats = Stats::load().unwrap_or_else(|e| {
+            println!("{}{}", constants::strings::STATS_ERROR, e);
+            Stats::default()
+        });
+
+        let achievements = Achievements::load().unwrap_or_else(|e| {
+            println!("{}{}", constants::strings::ACHIEVEMENTS_ERROR, e);
+            Achievements::default()
+        });
+
+        let leaderboards = Leaderboards::load().unwrap_or_else(|e| {
+            println!("{}{}", constants::strings::LEADERBOARDS_ERROR, e);
+            Leaderboards::default()
+        });
+
+        let leaderboard = Leaderboard::load().unwrap_or_else(|e| {
+            println!("{}{}", constants::strings::DAILY_ERROR, e);
+            Leaderboard::default()
+        });
+
+        let mut main_menu = Menu::new(
+            constants::strings::TITLE_TEXT,
+            iter::once(Widget::Choice {
+                label: String::from(constants::mode::LABEL),
+                options: constants::mode::NAMES.iter().map(|s| s.to_string()).collect(),
+                selected: 0,
+            })
+            .chain(
+                constants::main_menu::ITEMS
+                    .iter()
+                    .map(|s| Widget::Button(s.to_string())),
+            )
+            .collect(),
+        );
+        main_menu.set_focus(1);
+
+        let mut game = Self {
             canvas,
             event_pump,
 
-            screen_bounds,
+            controller_subsystem,
+            controllers: Vec::new(),
 
-            space_released: true,
-            next_asteroid_spawn: Self::get_next_asteroid_spawn(0),
-            next_alien_spawn: 0,
-            next_black_hole_spawn: 0,
+            screen_bounds,
 
-            lives: constants::player::START_LIVES,
-            next_life_points: constants::player::POINTS_PER_LIFE,
+            input: Input::default(),
 
-            score: 0,
             pb,
 
-            player: p,
+            sessions: vec![Session::new(screen_bounds, 1, 0)],
+            current_session: 0,
+
+            demo: attract::new_demo(screen_bounds, settings.attract_ship),
+            autopilot: Autopilot::new(0),
+            attract: Attract::new(),
+
+            client: None,
+            peer: None,
+            spectator: None,
+            broadcaster: None,
+
+            debug: DebugOverlay::new(),
+            frame_dt: 0.0,
 
-            particles: Vec::new(),
-            bullets: Vec::new(),
-            asteroids: Vec::new(),
-            aliens: Vec::new(),
-            black_holes: Vec::new(),
+            console: Console::new(),
+            god: false,
+            unranked: false,
+            time_scale: 1.0,
+
+            settings,
+            locale: Locale::default(),
+            audio,
+
+            stats: vec![Stats::default()],
+            lifetime_stats,
+            achievements,
+
+            mode: Mode::Classic,
+            leaderboards,
+            sandbox: None,
+            tutorial: None,
+
+            leaderboard,
+            replay: None,
+            daily: false,
+            accumulator: 0.0,
 
             state: GameState::MainMenu,
-            paused: false,
-        })
+            main_menu,
+            pause_menu: PauseMenu::new(),
+            settings_menu: SettingsMenu::new(),
+            stats_screen: StatsScreen::new(),
+            achievements_screen: AchievementsScreen::new(),
+            daily_screen: DailyScreen::new(),
+            leaderboard_screen: LeaderboardScreen::new(),
+            sandbox_menu: SandboxMenu::new(),
+            quit: false,
+        };
+
+        game.apply_settings()?;
+
+        Ok(game)
     }
 
     pub fn run(&mut self) {
         let mut last_tick = unsafe { SDL_GetTicks64() };
 
         'running: loop {
-            let new_size = self.canvas.window().drawable_size();
-            if new_size.0 > 0 && new_size.1 > 0 {
-                self.screen_bounds.set_width(new_size.0);
-                self.screen_bounds.set_height(new_size.1);
-            }
-
             for event in self.event_pump.poll_iter().collect::<Vec<Event>>() {
                 match event {
                     Event::Quit { .. } => break 'running,
@@ -134,261 +279,534 @@ impl Game {
                         keycode: Some(k), ..
                     } => self.handle_key_event(k, false),
 
+                    Event::TextInput { text, .. } => self.console.handle_text(&text),
+
+                    Event::ControllerDeviceAdded { which, .. } => {
+                        match self.controller_subsystem.open(which) {
+                            Ok(controller) => self.controllers.push(controller),
+                            Err(e) => println!("{}{}", constants::strings::CONTROLLER_ERROR, e),
+                        }
+                    }
+                    Event::ControllerButtonDown { button, .. } => self.handle_button_event(button),
+
+                    Event::Window {
+                        win_event: WindowEvent::FocusLost,
+                        ..
+                    } => {
+                        if matches!(self.state, GameState::InGame) {
+                            self.pause();
+                        }
+                    }
+                    Event::Window {
+                        win_event: WindowEvent::SizeChanged(..),
+                        ..
+                    } => self.resize(),
+
                     _ => {}
                 }
             }
 
+            if self.quit {
+                break 'running;
+            }
+
             let now = unsafe { SDL_GetTicks64() };
             let dt = (now - last_tick) as f32 / 1000.0;
             last_tick = now;
 
+            self.frame_dt = if self.pause_menu.is_open() {
+                constants::tuning::STEP_DT
+            } else {
+                dt * self.time_scale
+            };
+            self.debug.record_frame(dt);
+            self.achievements.update(dt);
+            self.leaderboard.poll();
+
+            if let Some(broadcaster) = &mut self.broadcaster {
+                broadcaster.update(&self.sessions[self.current_session], dt);
+            }
+
+            if !matches!(self.state, GameState::InGame) || self.pause_menu.is_open() {
+                self.audio.stop();
+            }
+
             match self.state {
                 GameState::MainMenu => {
+                    self.attract.update(dt, &self.leaderboard);
+                    self.tick_demo(dt);
+
                     if let Err(e) = self.render_main_menu() {
                         println!("{}{}", constants::strings::RENDER_ERROR, e);
                     }
                 }
+                GameState::PlayerReady => {
+                    if let Err(e) = self.render_player_ready() {
+                        println!("{}{}", constants::strings::RENDER_ERROR, e);
+                    }
+                }
                 GameState::InGame => {
-                    if !self.paused {
-                        self.tick_game(dt);
+                    if !self.pause_menu.is_open() {
+                        if self.replay.is_some() {
+                            self.tick_daily(dt);
+                        } else {
+                            self.tick_game(dt * self.time_scale);
+                        }
                     }
 
                     if let Err(e) = self.render_game() {
                         println!("{}{}", constants::strings::RENDER_ERROR, e);
                     }
                 }
+                GameState::GameOver => {
+                    if let Err(e) = self.render_game_over() {
+                        println!("{}{}", constants::strings::RENDER_ERROR, e);
+                    }
+                }
+                GameState::Online => {
+                    if let Some(client) = &mut self.client {
+                        client.update(dt, self.input);
+                    }
+
+                    if let Err(e) = self.render_online() {
+                        println!("{}{}", constants::strings::RENDER_ERROR, e);
+                    }
+                }
+                GameState::PeerToPeer => {
+                    if let Some(peer) = &mut self.peer {
+                        peer.update(dt, self.input);
+                    }
+
+                    if let Err(e) = self.render_peer_to_peer() {
+                        println!("{}{}", constants::strings::RENDER_ERROR, e);
+                    }
+                }
+                GameState::Spectating => {
+                    if let Some(spectator) = &mut self.spectator {
+                        spectator.update(dt, self.screen_bounds);
+                    }
+
+                    if let Err(e) = self.render_spectating() {
+                        println!("{}{}", constants::strings::RENDER_ERROR, e);
+                    }
+                }
             }
         }
     }
 
+    pub fn connect(&mut self, addr: &str) -> Result<(), String> {
+        self.client = Some(Client::connect(addr)?);
+        self.state = GameState::Online;
+
+        Ok(())
+    }
+
+    pub fn spectate(&mut self, addr: &str) -> Result<(), String> {
+        self.spectator = Some(Spectator::connect(addr)?);
+        self.state = GameState::Spectating;
+
+        Ok(())
+    }
+
+    pub fn broadcast(&mut self, port: u16) -> Result<(), String> {
+        self.broadcaster = Some(Broadcaster::new(port)?);
+        println!("{}{}", constants::strings::BROADCASTING_TEXT, port);
+
+        Ok(())
+    }
+
+    pub fn start_peer_to_peer(&mut self, transport: Box<dyn Transport>, player: usize, seed: u64) {
+        self.peer = Some(RollbackPeer::new(transport, player, seed, net::field_bounds()));
+        self.state = GameState::PeerToPeer;
+    }
+
     fn tick_game(&mut self, dt: f32) {
-        if unsafe { SDL_GetTicks64() } > self.next_asteroid_spawn {
-            self.next_asteroid_spawn = Self::get_next_asteroid_spawn(self.score);
+        let bounds = self.get_field_bounds();
+        self.sessions[self.current_session].tick(dt, bounds);
+        self.process_tick(dt, bounds);
+    }
 
-            let (x, y) = Asteroid::get_spawn_location(
-                self.player.get_x(),
-                self.player.get_y(),
-                self.screen_bounds,
-            );
+    fn process_tick(&mut self, dt: f32, bounds: Rect) {
+        let index = self.current_session;
+        let session = &self.sessions[index];
+        self.audio.update(session, dt, bounds);
+        self.stats[index].track(session, dt);
+
+        let events = session.get_events().to_vec();
+        let hits = events.iter().filter(|e| self.handle_event(index, e)).count();
 
-            let radius = rand::rng().random_range(constants::asteroid::SPAWN_RADIUS_RANGE);
+        if self.sandbox.is_none() && !self.unranked {
+            self.achievements.track(&self.sessions[index], &self.stats[index]);
+        }
 
-            self.asteroids.push(Asteroid::new(x, y, radius as f32));
+        if !self.daily {
+            for _ in 0..hits {
+                if self.current_session != index || !matches!(self.state, GameState::InGame) {
+                    break;
+                }
+                self.die();
+            }
         }
 
-        if unsafe { SDL_GetTicks64() } > self.next_alien_spawn
-            && constants::alien::MAX_ALIENS > self.aliens.len() as u32
-        {
-            let res = Alien::new(self.score, self.screen_bounds);
+        if matches!(self.state, GameState::InGame) && self.mode.is_time_up(&self.sessions[index]) {
+            self.time_up();
+        }
 
-            if let Some(alien) = res {
-                self.next_alien_spawn = Game::get_next_alien_spawn(self.score);
+        if let Some(tutorial) = &mut self.tutorial {
+            tutorial.update(&mut self.sessions[index], bounds, dt);
 
-                self.aliens.push(alien);
+            if tutorial.is_finished() {
+                self.tutorial = None;
+                self.finish_game();
+                self.state = GameState::MainMenu;
             }
         }
+    }
 
-        if unsafe { SDL_GetTicks64() } > self.next_black_hole_spawn
-            && self.score >= constants::black_hole::MIN_POINTS
-        {
-            self.next_black_hole_spawn = Game::get_next_black_hole_spawn(self.score);
-            self.black_holes.push(BlackHole::new(self.screen_bounds));
-        }
+    fn tick_daily(&mut self, dt: f32) {
+        let tick_dt = daily::get_tick_dt();
+        self.accumulator = (self.accumulator + dt).min(constants::net::MAX_CATCH_UP);
 
-        self.player.tick(dt, self.screen_bounds);
-        self.particles.append(&mut self.player.get_particles());
+        while self.accumulator >= tick_dt && matches!(self.state, GameState::InGame) {
+            self.accumulator -= tick_dt;
 
-        self.particles.retain(|p| p.is_alive());
-        self.particles
-            .iter_mut()
-            .for_each(|p| p.tick(dt, self.screen_bounds));
+            if let Some(replay) = &mut self.replay {
+                replay.record(self.input);
+            }
 
-        let mut to_die = false;
+            let over = daily::step(&mut self.sessions[self.current_session], self.input);
+            self.process_tick(tick_dt, net::field_bounds());
 
-        self.bullets.retain(|b| {
-            if !b.get_is_player_shot()
-                && point_intersects_polygon(b.get_location(), self.player.get_hitbox().as_slice())
-            {
-                to_die = true;
-                return false;
+            if over && matches!(self.state, GameState::InGame) {
+                self.game_over();
             }
-            b.is_alive() && !b.to_die
-        });
+        }
+    }
 
-        if to_die {
-            self.die();
+    fn has_fixed_field(&self) -> bool {
+        self.daily || self.broadcaster.is_some()
+    }
+
+    fn get_field_bounds(&self) -> Rect {
+        if self.has_fixed_field() {
+            net::field_bounds()
+        } else {
+            self.screen_bounds
         }
+    }
 
-        self.bullets.iter_mut().for_each(|b| {
-            b.tick(dt, self.screen_bounds);
-            self.particles.append(&mut b.get_particles_to_spawn())
-        });
+    fn handle_event(&mut self, index: usize, event: &GameEvent) -> bool {
+        let hit = matches!(event, GameEvent::PlayerHit { .. });
+        let invulnerable = !self.daily
+            && (self.god || !self.mode.can_die() || self.sandbox.is_some_and(|s| s.invulnerable));
+        if hit && invulnerable {
+            return false;
+        }
 
-        let mut asteroids_to_add: Vec<Asteroid> = Vec::new();
+        self.audio.handle_event(event);
+        self.stats[index].handle_event(event);
+        self.achievements.handle_event(event);
 
-        self.asteroids.retain(|a| {
-            let remove = self.bullets.iter_mut().any(|b| {
-                let line = b.get_physics_trail(dt);
-                let intersects = a
-                    .get_hitboxes(self.screen_bounds)
-                    .iter()
-                    .any(|hitbox| polygon::polygons_intersect(hitbox, line.as_slice()))
-                    && !b.to_die;
+        hit
+    }
 
-                if intersects {
-                    b.to_die = true;
+    fn set_time_scale(&mut self, scale: f32) {
+        self.time_scale = scale.clamp(
+            constants::tuning::MIN_TIME_SCALE,
+            constants::tuning::MAX_TIME_SCALE,
+        );
+        self.unranked |= self.time_scale != 1.0;
+    }
 
-                    self.score += (constants::asteroid::SCORE_PER_RADIUS / a.get_radius()) as u64;
-                    if self.score > self.next_life_points {
-                        self.next_life_points += constants::player::POINTS_PER_LIFE;
-                        self.lives += 1;
-                    }
-                }
+    fn change_time_scale(&mut self, faster: bool) {
+        let scales = constants::tuning::TIME_SCALES;
 
-                intersects
-            });
+        let next = if faster {
+            scales.iter().find(|&&s| s > self.time_scale)
+        } else {
+            scales.iter().rev().find(|&&s| s < self.time_scale)
+        };
 
-            if remove {
-                if let Some(mut asteroids) = a.check_split() {
-                    asteroids_to_add.append(&mut asteroids);
+        if let Some(&scale) = next {
+            self.set_time_scale(scale);
+        }
+    }
+
+    fn run_command(&mut self, command: Command) {
+        self.unranked |= !matches!(
+            command,
+            Command::TimeScale(None) | Command::Seed(None) | Command::Set(_, None)
+        );
+
+        let bounds = self.get_field_bounds();
+        let mut rng = rand::rng();
+        let session = &mut self.sessions[self.current_session];
+        let ok = String::from(constants::strings::OK_TEXT);
+
+        let result = match command {
+            Command::SpawnAsteroid { radius, x, y } => {
+                session.add_asteroid(Asteroid::new(x, y, radius, &mut rng));
+                Ok(ok)
+            }
+            Command::SpawnAlien(shooting_type) => {
+                let now = session.get_time();
+                session.add_alien(Alien::new_of_type(shooting_type, bounds, now, &mut rng));
+                Ok(ok)
+            }
+            Command::SpawnBlackHole => {
+                let now = session.get_time();
+                session.add_black_hole(BlackHole::new(bounds, now, &mut rng));
+                Ok(ok)
+            }
+            Command::SetScore(score) => {
+                if let Some(player) = session.get_player_mut(0) {
+                    player.set_score(score);
+                }
+                Ok(ok)
+            }
+            Command::God => {
+                self.god = !self.god;
+                Ok(String::from(if self.god {
+                    constants::strings::GOD_ON_TEXT
+                } else {
+                    constants::strings::GOD_OFF_TEXT
+                }))
+            }
+            Command::TimeScale(scale) => {
+                if let Some(scale) = scale {
+                    self.set_time_scale(scale);
+                }
+                Ok(format!("{}{}", constants::strings::TIME_SCALE_TEXT, self.time_scale))
+            }
+            Command::Seed(seed) => {
+                if let Some(seed) = seed {
+                    self.sessions[self.current_session] = if self.daily {
+                        daily::new_session(seed)
+                    } else {
+                        self.new_session(seed)
+                    };
                 }
-                self.particles
-                    .append(&mut Particle::generate_explosion_particles(
-                        a.get_x(),
-                        a.get_y(),
-                    ));
+                Ok(format!(
+                    "{}{}",
+                    constants::strings::SEED_TEXT,
+                    self.sessions[self.current_session].get_seed()
+                ))
             }
+            Command::Set(key, None) => session
+                .get_tuning()
+                .get(&key)
+                .map(|value| format!("{} {}", key, value))
+                .ok_or(format!("{}{}", constants::strings::UNKNOWN_KEY_ERROR, key)),
+            Command::Set(key, Some(value)) => session
+                .get_tuning_mut()
+                .set(&key, &value)
+                .map(|_| ok),
+        };
+
+        match result {
+            Ok(text) | Err(text) => self.console.print(&text),
+        }
+    }
+
+    fn tick_demo(&mut self, dt: f32) {
+        let input = self.autopilot.get_input(&self.demo, self.screen_bounds);
+        self.demo.set_input(0, input);
 
-            !remove
+        self.demo.tick(dt, self.screen_bounds).iter().for_each(|&i| {
+            self.demo.die(self.screen_bounds);
+            self.demo.respawn(i, self.screen_bounds);
         });
+    }
+
+    fn render_game(&mut self) -> Result<(), String> {
+        self.canvas.set_draw_color(Color::RGB(0, 0, 0));
+        self.canvas.clear();
 
-        self.asteroids.append(&mut asteroids_to_add);
-        self.asteroids
-            .iter_mut()
-            .for_each(|a| a.tick(dt, self.screen_bounds));
+        self.render_field()?;
 
-        if self.asteroids.iter().any(|a| {
-            a.get_hitboxes(self.screen_bounds).iter().any(|hitbox| {
-                polygon::polygons_intersect(hitbox, self.player.get_hitbox().as_slice())
-            })
-        }) {
-            self.die();
+        let session = &self.sessions[self.current_session];
+        if let Some(player) = session.get_player(0) {
+            font::render_text(player.get_score().to_string().as_str(), 10, 10, &mut self.canvas)?;
+            font::render_lives(player.get_lives(), &self.screen_bounds, &mut self.canvas)?;
         }
 
-        self.aliens.retain(|a| {
-            let remove = self.bullets.iter_mut().any(|b| {
-                let line = b.get_physics_trail(dt);
-                let intersects = a
-                    .get_hitboxes(self.screen_bounds)
-                    .iter()
-                    .any(|hitbox| polygon::polygons_intersect(hitbox, line.as_slice()))
-                    && !b.to_die;
+        if self.sessions.len() > 1 {
+            let player_str = format!(
+                "{} {}",
+                self.locale.translate(constants::strings::PLAYER_TEXT),
+                self.current_session + 1
+            );
+            font::render_text(
+                player_str.as_str(),
+                10,
+                (10 + 2 * constants::font::FONT_SIZE + constants::font::MARGIN) as i32,
+                &mut self.canvas,
+            )?;
+        }
 
-                if intersects {
-                    b.to_die = true;
+        ui::render_text(
+            self.pb.to_string().as_str(),
+            self.screen_bounds.width() as i32 - 10,
+            10,
+            Align::Right,
+            1.0,
+            &mut self.canvas,
+        )?;
 
-                    self.score += match a.get_type() {
-                        ShootingType::Random => constants::alien::random::POINTS,
-                        ShootingType::Current => constants::alien::current::POINTS,
-                        ShootingType::Future => constants::alien::future::POINTS,
-                    } as u64;
+        if let Some(remaining) = self.mode.get_remaining(session) {
+            let seconds = remaining.div_ceil(1000);
+            ui::render_text(
+                &format!("{}:{:02}", seconds / 60, seconds % 60),
+                self.screen_bounds.width() as i32 / 2,
+                10,
+                Align::Center,
+                constants::mode::TIMER_SCALE,
+                &mut self.canvas,
+            )?;
+        }
 
-                    if self.score > self.next_life_points {
-                        self.next_life_points += constants::player::POINTS_PER_LIFE;
-                        self.lives += 1;
-                    }
-                }
+        if self.time_scale != 1.0 && !self.daily {
+            ui::render_text(
+                format!(
+                    "{} {}",
+                    self.locale.translate(constants::strings::TIME_SCALE_TEXT.trim_end()),
+                    self.time_scale
+                )
+                .as_str(),
+                self.screen_bounds.width() as i32 - 10,
+                (10 + 2 * constants::font::FONT_SIZE + constants::font::MARGIN) as i32,
+                Align::Right,
+                1.0,
+                &mut self.canvas,
+            )?;
+        }
 
-                intersects
-            });
+        self.debug
+            .render_hud(&mut self.canvas, session, self.screen_bounds)?;
+        if let Some(tutorial) = &self.tutorial {
+            tutorial.render(
+                &mut self.canvas,
+                self.screen_bounds,
+                self.settings.get_text_scale(),
+                &self.locale,
+            )?;
+        }
+        self.achievements.render_toasts(
+            &mut self.canvas,
+            self.screen_bounds,
+            self.settings.get_text_scale(),
+            &self.locale,
+        )?;
+        if self.settings_menu.is_open() {
+            self.settings_menu.render(&mut self.canvas, self.screen_bounds, &self.locale)?;
+        } else {
+            self.pause_menu.render(&mut self.canvas, self.screen_bounds, &self.locale)?;
+        }
+        self.console.render(&mut self.canvas, self.screen_bounds)?;
 
-            if remove {
-                self.particles
-                    .append(&mut Particle::generate_explosion_particles(
-                        a.get_x(),
-                        a.get_y(),
-                    ));
-            }
+        self.canvas.present();
 
-            !remove
-        });
+        Ok(())
+    }
 
-        self.aliens.iter_mut().for_each(|a| {
-            a.tick(
-                dt,
-                self.screen_bounds,
-                self.score,
-                self.player.get_pos_and_vel(),
-            );
+    fn render_field(&mut self) -> Result<(), String> {
+        let session = &self.sessions[self.current_session];
 
-            if let Some(bullet) = a.get_bullet() {
-                self.bullets.push(bullet);
-            }
-        });
+        if !self.has_fixed_field() {
+            session.render(&mut self.canvas, self.screen_bounds)?;
+            return self
+                .debug
+                .render_world(&mut self.canvas, session, self.screen_bounds, self.frame_dt);
+        }
 
-        self.black_holes.retain(|b| b.is_alive());
+        let scale = net::field_scale(self.screen_bounds);
+        self.canvas.set_scale(scale, scale)?;
+        session.render(&mut self.canvas, net::field_bounds())?;
+        self.debug
+            .render_world(&mut self.canvas, session, net::field_bounds(), daily::get_tick_dt())?;
+        self.canvas.set_scale(1.0, 1.0)
+    }
 
-        self.black_holes.iter_mut().for_each(|b| {
-            b.tick(dt);
+    fn render_online(&mut self) -> Result<(), String> {
+        self.canvas.set_draw_color(Color::RGB(0, 0, 0));
+        self.canvas.clear();
 
-            let force = b.get_force(self.player.get_x(), self.player.get_y(), dt);
-            self.player.apply_force(force);
-        });
+        if let Some(client) = &self.client {
+            client.render(&mut self.canvas, self.screen_bounds)?;
+
+            let player = client.get_player();
+            font::render_text(player.get_score().to_string().as_str(), 10, 10, &mut self.canvas)?;
+            font::render_lives(player.get_lives(), &self.screen_bounds, &mut self.canvas)?;
+        }
+
+        self.canvas.present();
+
+        Ok(())
     }
 
-    fn render_game(&mut self) -> Result<(), String> {
+    fn render_peer_to_peer(&mut self) -> Result<(), String> {
         self.canvas.set_draw_color(Color::RGB(0, 0, 0));
         self.canvas.clear();
 
-        if self.paused {
-            let text_len = constants::strings::PAUSED_TEXT.len() as u32;
-
-            let text_width = (text_len * constants::font::FONT_SIZE) + ((text_len - 1) * constants::font::MARGIN);
-            font::render_text(
-                constants::strings::PAUSED_TEXT,
-                (self.screen_bounds.width() - text_width) as i32 / 2,
-                (self.screen_bounds.height() / 2 - constants::font::FONT_SIZE) as i32,
+        if let Some(peer) = &self.peer {
+            let scale = net::field_scale(self.screen_bounds);
+            self.canvas.set_scale(scale, scale)?;
+            peer.get_session()
+                .render(&mut self.canvas, net::field_bounds())?;
+            self.debug.render_world(
                 &mut self.canvas,
+                peer.get_session(),
+                net::field_bounds(),
+                constants::net::TICK_MS as f32 / 1000.0,
             )?;
+            self.canvas.set_scale(1.0, 1.0)?;
 
-            self.canvas.present();
+            if let Some(player) = peer.get_session().get_player(peer.get_local_player()) {
+                font::render_text(player.get_score().to_string().as_str(), 10, 10, &mut self.canvas)?;
+                font::render_lives(player.get_lives(), &self.screen_bounds, &mut self.canvas)?;
+            }
 
-            return Ok(());
+            self.debug
+                .render_hud(&mut self.canvas, peer.get_session(), self.screen_bounds)?;
         }
 
-        self.player.render(&mut self.canvas, self.screen_bounds)?;
+        self.canvas.present();
 
-        self.particles
-            .iter()
-            .try_for_each(|p| p.render(&mut self.canvas))?;
+        Ok(())
+    }
 
-        self.bullets
-            .iter()
-            .try_for_each(|b| b.render(&mut self.canvas))?;
+    fn render_spectating(&mut self) -> Result<(), String> {
+        self.canvas.set_draw_color(Color::RGB(0, 0, 0));
+        self.canvas.clear();
 
-        self.asteroids
-            .iter()
-            .try_for_each(|a| a.render(&mut self.canvas, self.screen_bounds))?;
+        if let Some(spectator) = &self.spectator {
+            spectator.render(&mut self.canvas, self.screen_bounds, &self.locale)?;
+        }
 
-        self.aliens
-            .iter()
-            .try_for_each(|a| a.render(&mut self.canvas, self.screen_bounds))?;
+        self.canvas.present();
 
-        self.black_holes
-            .iter()
-            .try_for_each(|b| b.render(&mut self.canvas, self.screen_bounds))?;
-
-        font::render_text(self.score.to_string().as_str(), 10, 10, &mut self.canvas)?;
-        let pb_str = self.pb.to_string();
-        let pb_length = (pb_str.len() as u32 * constants::font::FONT_SIZE)
-            + ((pb_str.len() - 1) as u32 * constants::font::MARGIN);
-        font::render_text(
-            pb_str.as_str(),
-            (self.screen_bounds.width() - pb_length - 10) as i32,
-            10,
+        Ok(())
+    }
+
+    fn render_game_over(&mut self) -> Result<(), String> {
+        self.canvas.set_draw_color(Color::RGB(0, 0, 0));
+        self.canvas.clear();
+
+        self.render_field()?;
+        self.stats_screen
+            .render(&mut self.canvas, self.screen_bounds, &self.locale)?;
+        self.daily_screen.render(
             &mut self.canvas,
+            self.screen_bounds,
+            &self.locale,
+            &self.leaderboard,
+        )?;
+        self.achievements.render_toasts(
+            &mut self.canvas,
+            self.screen_bounds,
+            self.settings.get_text_scale(),
+            &self.locale,
         )?;
-        font::render_lives(self.lives, &self.screen_bounds, &mut self.canvas)?;
 
         self.canvas.present();
 
@@ -399,25 +817,84 @@ impl Game {
         self.canvas.set_draw_color(Color::RGB(0, 0, 0));
         self.canvas.clear();
 
-        self.canvas.set_draw_color(Color::RGB(255, 255, 255));
+        if !self.attract.is_playing() {
+            self.demo.render(&mut self.canvas, self.screen_bounds)?;
+        }
+
+        if self.attract.is_active() {
+            self.attract.render(
+                &mut self.canvas,
+                self.screen_bounds,
+                &self.locale,
+                &self.leaderboards,
+                &self.settings.bindings,
+            )?;
+            self.canvas.present();
+
+            return Ok(());
+        }
 
-        let string_width = (constants::strings::START_TEXT.len() as u32
-            * constants::font::FONT_SIZE)
-            + ((constants::strings::START_TEXT.len() - 1) as u32 * constants::font::MARGIN);
+        if self.settings_menu.is_open() {
+            self.settings_menu.render(&mut self.canvas, self.screen_bounds, &self.locale)?;
+        } else if self.stats_screen.is_open() {
+            self.stats_screen
+                .render(&mut self.canvas, self.screen_bounds, &self.locale)?;
+        } else if self.achievements_screen.is_open() {
+            self.achievements_screen.render(
+                &mut self.canvas,
+                self.screen_bounds,
+                &self.locale,
+                &self.achievements,
+            )?;
+        } else if self.daily_screen.is_open() {
+            self.daily_screen.render(
+                &mut self.canvas,
+                self.screen_bounds,
+                &self.locale,
+                &self.leaderboard,
+            )?;
+        } else if self.leaderboard_screen.is_open() {
+            self.leaderboard_screen.render(
+                &mut self.canvas,
+                self.screen_bounds,
+                &self.locale,
+                &self.leaderboards,
+            )?;
+        } else if self.sandbox_menu.is_open() {
+            self.sandbox_menu
+                .render(&mut self.canvas, self.screen_bounds, &self.locale)?;
+        } else {
+            self.main_menu.render(&mut self.canvas, self.screen_bounds, &self.locale)?;
+        }
+
+        self.canvas.present();
+
+        Ok(())
+    }
 
-        if self.screen_bounds.width() < string_width
-            || self.screen_bounds.height() / 2 < constants::font::FONT_SIZE
-        {
+    fn render_player_ready(&mut self) -> Result<(), String> {
+        self.canvas.set_draw_color(Color::RGB(0, 0, 0));
+        self.canvas.clear();
+
+        let text = format!(
+            "{} {} {}",
+            self.locale.translate(constants::strings::PLAYER_TEXT),
+            self.current_session + 1,
+            self.locale.translate(constants::strings::READY_TEXT)
+        );
+
+        if self.screen_bounds.width() < ui::measure_text(&text, 1.0).0 {
             self.canvas.present();
 
             return Err(String::from(constants::strings::WINDOW_SIZE_ERROR));
         }
 
-        let x = (self.screen_bounds.width() - string_width) / 2;
-        font::render_text(
-            constants::strings::START_TEXT,
-            x as i32,
+        ui::render_text(
+            text.as_str(),
+            self.screen_bounds.width() as i32 / 2,
             (self.screen_bounds.height() / 2 - constants::font::FONT_SIZE) as i32,
+            Align::Center,
+            1.0,
             &mut self.canvas,
         )?;
 
@@ -427,103 +904,624 @@ impl Game {
     }
 
     fn handle_key_event(&mut self, key: Keycode, pressed: bool) {
+        if key == Keycode::F3 && pressed {
+            self.debug.toggle();
+        }
+
+        if pressed && self.attract.wake() {
+            return;
+        }
+
+        if self.settings_menu.is_open() {
+            if pressed && let Some(event) = self.settings_menu.handle_key_event(key) {
+                self.handle_settings_event(event);
+            }
+            return;
+        }
+
+        if self.stats_screen.is_open() {
+            if pressed && let Some(action) = MenuInput::from_key(key).and_then(|i| self.stats_screen.handle_input(i)) {
+                self.run_stats_action(action);
+            }
+            return;
+        }
+
+        if self.achievements_screen.is_open() {
+            if pressed && let Some(input) = MenuInput::from_key(key) {
+                self.achievements_screen.handle_input(input);
+            }
+            return;
+        }
+
+        if self.daily_screen.is_open() {
+            if pressed && let Some(action) = MenuInput::from_key(key).and_then(|i| self.daily_screen.handle_input(i)) {
+                self.run_daily_action(action);
+            }
+            return;
+        }
+
+        if self.leaderboard_screen.is_open() {
+            if pressed && let Some(input) = MenuInput::from_key(key) {
+                self.leaderboard_screen.handle_input(input);
+            }
+            return;
+        }
+
+        if self.sandbox_menu.is_open() {
+            if pressed && let Some(action) = MenuInput::from_key(key).and_then(|i| self.sandbox_menu.handle_input(i)) {
+                self.run_sandbox_action(action);
+            }
+            return;
+        }
+
         match self.state {
             GameState::MainMenu => {
-                if key == Keycode::SPACE {
+                if key == Keycode::NUM_2 && pressed {
+                    self.start_game(constants::player::MAX_PLAYERS, self.get_selected_mode());
+                } else if pressed && let Some(input) = MenuInput::from_key(key) {
+                    self.handle_main_menu_input(input);
+                }
+            }
+            GameState::PlayerReady => {
+                if key == Keycode::SPACE && pressed {
                     self.state = GameState::InGame;
-                    self.player.set_location(
-                        self.screen_bounds.width() as f32 / 2.0,
-                        self.screen_bounds.height() as f32 / 2.0,
-                    );
                 }
             }
             GameState::InGame => {
-                if key == Keycode::P && pressed {
-                    self.paused = !self.paused;
+                if key == Keycode::BACKQUOTE && pressed && !self.daily {
+                    self.console.toggle();
+                    self.input = Input::default();
+                    self.sessions[self.current_session].set_input(0, self.input);
+                    return;
                 }
 
-                if self.paused {
+                if self.console.is_open() {
+                    if key == Keycode::ESCAPE && pressed {
+                        self.console.toggle();
+                    } else if pressed && let Some(command) = self.console.handle_key_event(key) {
+                        self.run_command(command);
+                    }
                     return;
                 }
 
-                self.player.handle_key_event(key, pressed);
+                if self.pause_menu.is_open() {
+                    if !pressed {
+                        return;
+                    }
 
-                if key == Keycode::SPACE {
-                    if pressed && self.space_released {
-                        self.bullets.push(self.player.shoot_bullet());
+                    if key == Keycode::P {
+                        self.pause_menu.close();
+                    } else if key == Keycode::N && !self.daily {
+                        self.unranked = true;
+                        self.tick_game(constants::tuning::STEP_DT);
+                    } else if let Some(action) = MenuInput::from_key(key).and_then(|i| self.pause_menu.handle_input(i)) {
+                        self.run_pause_action(action);
+                    }
+                    return;
+                }
+
+                if pressed {
+                    match key {
+                        Keycode::P | Keycode::ESCAPE => self.pause(),
+                        _ if self.daily => {}
+                        Keycode::LEFTBRACKET => self.change_time_scale(false),
+                        Keycode::RIGHTBRACKET => self.change_time_scale(true),
+                        Keycode::BACKSLASH => self.set_time_scale(1.0),
+                        _ => {}
                     }
 
-                    self.space_released = !pressed;
+                    if self.pause_menu.is_open() {
+                        return;
+                    }
+                }
+
+                self.input.handle_key_event(key, pressed, &self.settings.bindings);
+                self.sessions[self.current_session].set_input(0, self.input);
+            }
+            GameState::GameOver => {}
+            GameState::Online | GameState::PeerToPeer => {
+                self.input.handle_key_event(key, pressed, &self.settings.bindings)
+            }
+            GameState::Spectating => {
+                if let Some(spectator) = &mut self.spectator {
+                    spectator.handle_key_event(key, pressed);
                 }
             }
         }
     }
 
-    fn get_next_asteroid_spawn(score: u64) -> u64 {
-        let delay = constants::asteroid::SPAWN_DELAYS
-            .iter()
-            .map(|d| if score >= d[0] { d[1] } else { u64::MAX })
-            .min()
-            .unwrap();
+    fn handle_main_menu_input(&mut self, input: MenuInput) {
+        match self.main_menu.handle_input(input) {
+            Some(MenuEvent::Activated(1)) => self.start_game(1, self.get_selected_mode()),
+            Some(MenuEvent::Activated(2)) => {
+                self.start_game(constants::player::MAX_PLAYERS, self.get_selected_mode())
+            }
+            Some(MenuEvent::Activated(3)) => self
+                .daily_screen
+                .open(&mut self.leaderboard, &daily::get_today()),
+            Some(MenuEvent::Activated(4)) => self.sandbox_menu.open(),
+            Some(MenuEvent::Activated(5)) => self.start_tutorial(),
+            Some(MenuEvent::Activated(6)) => self.leaderboard_screen.open(self.get_selected_mode()),
+            Some(MenuEvent::Activated(7)) => self.stats_screen.open_lifetime(&self.lifetime_stats),
+            Some(MenuEvent::Activated(8)) => self.achievements_screen.open(),
+            Some(MenuEvent::Activated(9)) => self.settings_menu.open(&self.settings),
+            Some(MenuEvent::Activated(10) | MenuEvent::Back) => self.quit = true,
+            _ => {}
+        }
+    }
+
+    fn get_selected_mode(&self) -> Mode {
+        Mode::from_index(self.main_menu.get_choice(0))
+    }
+
+    fn handle_settings_event(&mut self, event: SettingsEvent) {
+        if event == SettingsEvent::Changed {
+            self.settings = *self.settings_menu.get_settings();
+
+            if let Err(e) = self.settings.save() {
+                println!("{}{}", constants::strings::SAVE_SETTINGS_ERROR, e);
+            }
+
+            if let Err(e) = self.apply_settings() {
+                println!("{}{}", constants::strings::SETTINGS_ERROR, e);
+            }
+        }
+    }
+
+    fn resize(&mut self) {
+        let (width, height) = self.canvas.window().drawable_size();
+        if width == 0 || height == 0 || (width, height) == self.screen_bounds.size() {
+            return;
+        }
+
+        self.screen_bounds = Rect::new(0, 0, width, height);
+        self.demo = attract::new_demo(self.screen_bounds, self.settings.attract_ship);
+    }
+
+    fn apply_settings(&mut self) -> Result<(), String> {
+        let fullscreen = if self.settings.fullscreen {
+            FullscreenType::Desktop
+        } else {
+            FullscreenType::Off
+        };
+
+        let window = self.canvas.window_mut();
+        if window.fullscreen_state() != fullscreen {
+            window.set_fullscreen(fullscreen)?;
+        }
+
+        if !self.settings.fullscreen {
+            let (width, height) = self.settings.resolution;
+            window.set_size(width, height).map_err(|e| e.to_string())?;
+        }
+        self.resize();
+
+        if unsafe { SDL_RenderSetVSync(self.canvas.raw(), self.settings.vsync as i32) } != 0 {
+            return Err(sdl2::get_error());
+        }
+
+        if self.locale.get_code() != self.settings.locale {
+            self.locale = Locale::load(self.settings.locale)?;
+        }
+
+        let scale = self.settings.get_text_scale();
+        self.main_menu.set_scale(scale);
+        self.pause_menu.set_scale(scale);
+        self.stats_screen.set_scale(scale);
+        self.achievements_screen.set_scale(scale);
+        self.daily_screen.set_scale(scale);
+        self.leaderboard_screen.set_scale(scale);
+        self.sandbox_menu.set_scale(scale);
+        self.attract.set_scale(scale);
+
+        if self.demo.get_players().is_empty() == self.settings.attract_ship {
+            self.demo = attract::new_demo(self.screen_bounds, self.settings.attract_ship);
+        }
+
+        self.set_time_scale(self.settings.game_speed);
+
+        self.audio
+            .set_volume(self.settings.master_volume, self.settings.sfx_volume);
+
+        if !self.daily {
+            self.unranked |= !self.settings.has_standard_rules();
+            self.sessions.iter_mut().for_each(|s| {
+                self.settings.apply_tuning(s.get_tuning_mut());
+                self.mode.apply_tuning(s.get_tuning_mut());
+            });
+        }
+
+        Ok(())
+    }
+
+    fn handle_button_event(&mut self, button: Button) {
+        if self.attract.wake() {
+            return;
+        }
+
+        if self.settings_menu.is_open() {
+            if let Some(event) = MenuInput::from_button(button).and_then(|i| self.settings_menu.handle_input(i)) {
+                self.handle_settings_event(event);
+            }
+            return;
+        }
+
+        if self.stats_screen.is_open() {
+            if let Some(action) = MenuInput::from_button(button).and_then(|i| self.stats_screen.handle_input(i)) {
+                self.run_stats_action(action);
+            }
+            return;
+        }
 
-        if delay == u64::MAX {
-            return 0;
+        if self.achievements_screen.is_open() {
+            if let Some(input) = MenuInput::from_button(button) {
+                self.achievements_screen.handle_input(input);
+            }
+            return;
+        }
+
+        if self.daily_screen.is_open() {
+            if let Some(action) = MenuInput::from_button(button).and_then(|i| self.daily_screen.handle_input(i)) {
+                self.run_daily_action(action);
+            }
+            return;
+        }
+
+        if self.leaderboard_screen.is_open() {
+            if let Some(input) = MenuInput::from_button(button) {
+                self.leaderboard_screen.handle_input(input);
+            }
+            return;
+        }
+
+        if self.sandbox_menu.is_open() {
+            if let Some(action) = MenuInput::from_button(button).and_then(|i| self.sandbox_menu.handle_input(i)) {
+                self.run_sandbox_action(action);
+            }
+            return;
+        }
+
+        if matches!(self.state, GameState::MainMenu) {
+            if let Some(input) = MenuInput::from_button(button) {
+                self.handle_main_menu_input(input);
+            }
+            return;
+        }
+
+        if !matches!(self.state, GameState::InGame) || self.console.is_open() {
+            return;
+        }
+
+        if !self.pause_menu.is_open() {
+            if button == Button::Start {
+                self.pause();
+            }
+            return;
+        }
+
+        if button == Button::Start {
+            self.pause_menu.close();
+        } else if let Some(action) = MenuInput::from_button(button).and_then(|i| self.pause_menu.handle_input(i)) {
+            self.run_pause_action(action);
+        }
+    }
+
+    fn pause(&mut self) {
+        if self.pause_menu.is_open() {
+            return;
+        }
+
+        self.pause_menu.open();
+        self.input = Input::default();
+        self.sessions[self.current_session].set_input(0, self.input);
+    }
+
+    fn run_pause_action(&mut self, action: PauseAction) {
+        match action {
+            PauseAction::Resume => {}
+            PauseAction::Settings => self.settings_menu.open(&self.settings),
+            PauseAction::Restart if self.daily => {
+                self.finish_game();
+                self.state = GameState::MainMenu;
+            }
+            PauseAction::Restart => {
+                self.finish_game();
+                self.restart();
+            }
+            PauseAction::QuitToMenu => {
+                self.finish_game();
+                self.state = GameState::MainMenu;
+            }
+            PauseAction::Exit => {
+                self.finish_game();
+                self.quit = true;
+            }
+        }
+    }
+
+    fn run_stats_action(&mut self, action: StatsAction) {
+        match action {
+            StatsAction::PlayAgain => self.restart(),
+            StatsAction::Close => self.state = GameState::MainMenu,
         }
+    }
 
-        (unsafe { SDL_GetTicks64() } as u64) + delay
+    fn run_daily_action(&mut self, action: DailyAction) {
+        match action {
+            DailyAction::Play => self.start_daily(),
+            DailyAction::Close => self.state = GameState::MainMenu,
+        }
     }
 
-    fn get_next_alien_spawn(score: u64) -> u64 {
-        let delay = constants::alien::SPAWN_DELAYS
+    fn run_sandbox_action(&mut self, action: SandboxAction) {
+        if let SandboxAction::Start(sandbox) = action {
+            self.start_sandbox(sandbox);
+        }
+    }
+
+    fn finish_game(&mut self) {
+        if self.sandbox.is_some() {
+            self.stats = vec![Stats::default(); self.sessions.len()];
+            return;
+        }
+
+        if !self.unranked {
+            self.save_high_score();
+        }
+
+        if let Some(mut replay) = self.replay.take() {
+            replay.finish(&self.sessions[self.current_session]);
+            self.save_daily(&replay);
+        } else if !self.daily && !self.unranked {
+            self.submit_scores();
+        }
+
+        if !self.unranked {
+            self.stats
+                .iter()
+                .filter(|s| !s.is_empty())
+                .for_each(|s| self.lifetime_stats.add(s));
+
+            if let Err(e) = self.lifetime_stats.save() {
+                println!("{}{}", constants::strings::SAVE_STATS_ERROR, e);
+            }
+        }
+        self.stats = vec![Stats::default(); self.sessions.len()];
+    }
+
+    fn game_over(&mut self) {
+        let stats = self.stats.clone();
+        self.finish_game();
+
+        if self.daily {
+            self.daily_screen
+                .open(&mut self.leaderboard, &daily::get_today());
+        } else {
+            self.stats_screen.open_game_over(&stats);
+        }
+        self.state = GameState::GameOver;
+    }
+
+    fn submit_scores(&mut self) {
+        let scores = self
+            .sessions
             .iter()
-            .map(|d| if score >= d[0] { d[1] } else { u64::MAX })
-            .min()
-            .unwrap();
+            .zip(&self.stats)
+            .filter(|(_, stats)| !stats.is_empty())
+            .filter_map(|(session, _)| session.get_player(0).map(|p| p.get_score()))
+            .filter(|&score| score > 0)
+            .collect::<Vec<u64>>();
+
+        if scores.is_empty() {
+            return;
+        }
+
+        scores.into_iter().for_each(|score| {
+            self.leaderboards.submit(self.mode, score);
+        });
+
+        if let Err(e) = self.leaderboards.save() {
+            println!("{}{}", constants::strings::SAVE_LEADERBOARDS_ERROR, e);
+        }
+    }
 
-        if delay == u64::MAX {
-            return 0;
+    fn save_daily(&mut self, replay: &Replay) {
+        let file = daily::get_replay_file(replay.get_date());
+        let saved = daily::get_replay_path(&file)
+            .ok_or(String::from(constants::strings::CONFIG_DIR_ERROR))
+            .and_then(|path| replay.save(&path));
+
+        if let Err(e) = &saved {
+            println!("{}{}", constants::strings::SAVE_REPLAY_ERROR, e);
         }
 
-        (unsafe { SDL_GetTicks64() } as u64) + delay
+        self.leaderboard
+            .record(replay.get_date(), replay.get_score(), saved.err().map(|_| false));
+
+        if let Err(e) = self.leaderboard.save() {
+            println!("{}{}", constants::strings::SAVE_DAILY_ERROR, e);
+        }
     }
 
-    fn get_next_black_hole_spawn(score: u64) -> u64 {
-        let delay = constants::black_hole::SPAWN_DELAYS
+    fn save_high_score(&mut self) {
+        let best = self
+            .sessions
             .iter()
-            .map(|d| if score >= d[0] { d[1] } else { u64::MAX })
-            .min()
-            .unwrap();
+            .filter_map(|s| s.get_player(0).map(|p| p.get_score()))
+            .max()
+            .unwrap_or(0);
 
-        if delay == u64::MAX {
-            return 0;
+        if best > self.pb {
+            if let Err(e) = high_score::save_score(best) {
+                println!("{}{}", constants::strings::HIGH_SCORE_ERROR, e);
+            }
+
+            self.pb = best;
         }
+    }
 
-        (unsafe { SDL_GetTicks64() } as u64) + delay
+    fn new_session(&self, seed: u64) -> Session {
+        let bounds = self.get_field_bounds();
+        let mut session = Session::new(bounds, 1, seed);
+        self.settings.apply_tuning(session.get_tuning_mut());
+        self.mode.apply(&mut session, self.settings.start_lives);
+        session.set_hazards(self.sandbox.map(|s| s.hazards));
+        if let Some(player) = session.get_player_mut(0) {
+            player.set_location(bounds.width() as f32 / 2.0, bounds.height() as f32 / 2.0);
+        }
+        session
+    }
+
+    fn start_game(&mut self, players: usize, mode: Mode) {
+        self.mode = mode;
+        self.sandbox = None;
+        self.daily = false;
+        self.sessions = (0..players)
+            .map(|_| self.new_session(rand::rng().random()))
+            .collect();
+        self.current_session = 0;
+        self.stats = vec![Stats::default(); players];
+        self.achievements.reset_progress();
+        self.input = Input::default();
+        self.replay = None;
+        self.tutorial = None;
+        self.god = false;
+        self.unranked = !self.settings.has_standard_rules();
+        self.set_time_scale(self.settings.game_speed);
+
+        self.state = if players > 1 {
+            GameState::PlayerReady
+        } else {
+            GameState::InGame
+        };
+    }
+
+    fn start_sandbox(&mut self, sandbox: Sandbox) {
+        self.start_game(1, Mode::Classic);
+        self.sandbox = Some(sandbox);
+        self.sessions[0].set_hazards(Some(sandbox.hazards));
+    }
+
+    fn start_tutorial(&mut self) {
+        self.start_sandbox(Sandbox {
+            hazards: Hazards {
+                asteroid_sizes: [false; 3],
+                ..Hazards::default()
+            },
+            invulnerable: false,
+            infinite_lives: true,
+        });
+        self.tutorial = Some(Tutorial::new());
+    }
+
+    fn restart(&mut self) {
+        if self.tutorial.is_some() {
+            self.start_tutorial();
+            return;
+        }
+
+        match self.sandbox {
+            Some(sandbox) => self.start_sandbox(sandbox),
+            None => self.start_game(self.sessions.len(), self.mode),
+        }
+    }
+
+    fn start_daily(&mut self) {
+        let today = daily::get_today();
+        if self.leaderboard.has_played(&today) {
+            return;
+        }
+
+        let replay = Replay::new(&today);
+        self.mode = Mode::Classic;
+        self.sandbox = None;
+        self.tutorial = None;
+        self.sessions = vec![daily::new_session(replay.get_seed())];
+        self.current_session = 0;
+        self.stats = vec![Stats::default()];
+        self.achievements.reset_progress();
+        self.input = Input::default();
+        self.god = false;
+        self.unranked = false;
+        self.accumulator = 0.0;
+
+        self.leaderboard.record(&today, 0, Some(false));
+        if let Err(e) = self.leaderboard.save() {
+            println!("{}{}", constants::strings::SAVE_DAILY_ERROR, e);
+        }
+
+        self.replay = Some(replay);
+        self.daily = true;
+        self.state = GameState::InGame;
     }
 
     fn die(&mut self) {
-        self.player.die(self.screen_bounds);
+        let players = self.sessions.len();
+        let bounds = self.get_field_bounds();
+        let session = &mut self.sessions[self.current_session];
+        session.die(bounds);
 
-        self.asteroids.clear();
-        self.particles.clear();
-        self.bullets.clear();
-        self.aliens.clear();
-        self.black_holes.clear();
+        if self.sandbox.is_some_and(|s| s.infinite_lives) {
+            return;
+        }
 
-        if self.lives == 1 {
-            self.lives = constants::player::START_LIVES;
+        let Some(player) = session.get_player_mut(0) else {
+            return;
+        };
 
-            if self.score > self.pb {
-                if let Err(e) = high_score::save_score(self.score) {
-                    println!("{}{}", constants::strings::HIGH_SCORE_ERROR, e);
-                }
+        let last_life = player.get_lives() == 1;
+        if last_life && players == 1 {
+            self.game_over();
+            return;
+        }
 
-                self.pb = self.score;
-            }
+        player.lose_life();
 
-            self.score = 0;
-        } else {
-            self.lives -= 1;
+        if last_life {
+            self.save_high_score();
+        }
+
+        if players > 1 {
+            self.next_player();
+        }
+    }
+
+    fn time_up(&mut self) {
+        if self.sessions.len() == 1 {
+            self.game_over();
+            return;
+        }
+
+        if let Some(player) = self.sessions[self.current_session].get_player_mut(0) {
+            player.set_lives(0);
+        }
+
+        self.save_high_score();
+        self.next_player();
+    }
+
+    fn next_player(&mut self) {
+        self.input = Input::default();
+        self.sessions[self.current_session].set_input(0, self.input);
+
+        let count = self.sessions.len();
+        let next = (1..=count)
+            .map(|i| (self.current_session + i) % count)
+            .find(|&i| {
+                self.sessions[i]
+                    .get_player(0)
+                    .is_some_and(|p| p.get_lives() > 0)
+            });
+
+        match next {
+            Some(i) => {
+                if i != self.current_session {
+                    self.achievements.reset_progress();
+                }
+                self.current_session = i;
+                self.state = GameState::PlayerReady;
+            }
+            None => self.game_over(),
         }
     }
 }
@@ -0,0 +1,90 @@
+use sdl2::controller::Button;
+use sdl2::keyboard::Keycode;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum MenuInput {
+    Up,
+    Down,
+    Left,
+    Right,
+    Select,
+    Back,
+}
+
+impl MenuInput {
+    pub fn from_key(key: Keycode) -> Option<Self> {
+        match key {
+            Keycode::W | Keycode::Up => Some(MenuInput::Up),
+            Keycode::S | Keycode::Down => Some(MenuInput::Down),
+            Keycode::A | Keycode::Left => Some(MenuInput::Left),
+            Keycode::D | Keycode::Right => Some(MenuInput::Right),
+            Keycode::RETURN | Keycode::KP_ENTER | Keycode::SPACE => Some(MenuInput::Select),
+            Keycode::ESCAPE | Keycode::BACKSPACE => Some(MenuInput::Back),
+            _ => None,
+        }
+    }
+
+    pub fn from_button(button: Button) -> Option<Self> {
+        match button {
+            Button::DPadUp => Some(MenuInput::Up),
+            Button::DPadDown => Some(MenuInput::Down),
+            Button::DPadLeft => Some(MenuInput::Left),
+            Button::DPadRight => Some(MenuInput::Right),
+            Button::A => Some(MenuInput::Select),
+            Button::B | Button::Back => Some(MenuInput::Back),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct KeyBindings {
+    pub left: Keycode,
+    pub right: Keycode,
+    pub thrust: Keycode,
+    pub fire: Keycode,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        KeyBindings {
+            left: Keycode::A,
+            right: Keycode::D,
+            thrust: Keycode::W,
+            fire: Keycode::SPACE,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Default, PartialEq)]
+pub struct Input {
+    pub left: bool,
+    pub right: bool,
+    pub thrust: bool,
+    pub fire: bool,
+}
+
+impl Input {
+    pub fn handle_key_event(&mut self, key: Keycode, pressed: bool, bindings: &KeyBindings) {
+        match key {
+            k if k == bindings.left || k == Keycode::Left => self.left = pressed,
+            k if k == bindings.right || k == Keycode::Right => self.right = pressed,
+            k if k == bindings.thrust || k == Keycode::Up => self.thrust = pressed,
+            k if k == bindings.fire => self.fire = pressed,
+            _ => {}
+        }
+    }
+
+    pub fn to_byte(self) -> u8 {
+        (self.left as u8) | (self.right as u8) << 1 | (self.thrust as u8) << 2 | (self.fire as u8) << 3
+    }
+
+    pub fn from_byte(byte: u8) -> Self {
+        Input {
+            left: byte & 1 != 0,
+            right: byte & 1 << 1 != 0,
+            thrust: byte & 1 << 2 != 0,
+            fire: byte & 1 << 3 != 0,
+        }
+    }
+}
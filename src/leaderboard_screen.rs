@@ -0,0 +1,120 @@
+use crate::constants;
+use crate::input::MenuInput;
+use crate::locale::Locale;
+use crate::mode::{Leaderboards, Mode};
+use crate::ui::{self, Align, Menu, MenuEvent, Widget};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::{BlendMode, Canvas};
+use sdl2::video::Window;
+
+pub struct LeaderboardScreen {
+    open: bool,
+    menu: Menu,
+    scale: f32,
+}
+
+impl Default for LeaderboardScreen {
+    fn default() -> Self {
+        LeaderboardScreen {
+            open: false,
+            menu: Menu::new(
+                "",
+                vec![
+                    Widget::Choice {
+                        label: String::from(constants::mode::LABEL),
+                        options: constants::mode::NAMES.iter().map(|s| s.to_string()).collect(),
+                        selected: 0,
+                    },
+                    Widget::Button(String::from(constants::pause::BACK_LABEL)),
+                ],
+            ),
+            scale: 1.0,
+        }
+    }
+}
+
+impl LeaderboardScreen {
+    pub fn new() -> Self {
+        LeaderboardScreen::default()
+    }
+
+    pub fn open(&mut self, mode: Mode) {
+        self.open = true;
+        self.menu.set_choice(0, mode.get_index());
+        self.menu.set_focus(0);
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale;
+        self.menu.set_scale(scale);
+    }
+
+    pub fn handle_input(&mut self, input: MenuInput) -> bool {
+        if matches!(
+            self.menu.handle_input(input),
+            Some(MenuEvent::Activated(1) | MenuEvent::Back)
+        ) {
+            self.close();
+            return true;
+        }
+
+        false
+    }
+
+    pub fn render(
+        &self,
+        canvas: &mut Canvas<Window>,
+        screen_bounds: Rect,
+        locale: &Locale,
+        leaderboards: &Leaderboards,
+    ) -> Result<(), String> {
+        if !self.open {
+            return Ok(());
+        }
+
+        canvas.set_blend_mode(BlendMode::Blend);
+        canvas.set_draw_color(Color::RGBA(0, 0, 0, constants::pause::DIM_ALPHA));
+        canvas.fill_rect(screen_bounds)?;
+        canvas.set_blend_mode(BlendMode::None);
+
+        let center = screen_bounds.x() + screen_bounds.width() as i32 / 2;
+        let mut y = screen_bounds.y() + screen_bounds.height() as i32 / constants::stats::TOP_MARGIN_DIVISOR;
+
+        let title = locale.translate(constants::strings::LEADERBOARDS_TEXT);
+        ui::render_text(title, center, y, Align::Center, self.scale, canvas)?;
+        y += ui::measure_text(title, self.scale).1 as i32 * 2;
+
+        let scale = self.scale * constants::stats::TABLE_SCALE;
+        let padding = (constants::font::MARGIN as f32 * scale) as i32;
+
+        let scores = leaderboards.get_scores(Mode::from_index(self.menu.get_choice(0)));
+        let ranks = (1..=scores.len())
+            .map(|i| format!("{}.", i))
+            .collect::<Vec<String>>()
+            .join("\n");
+        let values = scores
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        ui::render_text(&ranks, center - padding, y, Align::Right, scale, canvas)?;
+        ui::render_text(&values, center + padding, y, Align::Left, scale, canvas)?;
+
+        let menu_top = screen_bounds.y() + screen_bounds.height() as i32 * 3 / 4;
+        self.menu.render(
+            canvas,
+            Rect::new(screen_bounds.x(), menu_top, screen_bounds.width(), screen_bounds.height() / 4),
+            locale,
+        )
+    }
+}
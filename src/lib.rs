@@ -0,0 +1,39 @@
+pub mod achievements;
+pub mod achievements_screen;
+pub mod alien;
+pub mod asteroid;
+pub mod attract;
+pub mod audio;
+pub mod autopilot;
+pub mod black_hole;
+pub mod bullet;
+pub mod console;
+pub mod constants;
+pub mod daily;
+pub mod daily_screen;
+pub mod debug;
+pub mod env;
+pub mod event;
+pub mod font;
+pub mod game;
+pub mod high_score;
+pub mod input;
+pub mod leaderboard_screen;
+pub mod locale;
+pub mod mode;
+pub mod net;
+pub mod particle;
+pub mod pause;
+pub mod player;
+pub mod polygon;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod sandbox;
+pub mod session;
+pub mod settings;
+pub mod settings_menu;
+pub mod stats;
+pub mod stats_screen;
+pub mod tuning;
+pub mod tutorial;
+pub mod ui;
@@ -0,0 +1,46 @@
+use crate::constants;
+use std::collections::HashMap;
+
+pub struct Locale {
+    code: &'static str,
+    table: HashMap<String, String>,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale {
+            code: constants::locale::DEFAULT,
+            table: HashMap::new(),
+        }
+    }
+}
+
+impl Locale {
+    pub fn load(code: &str) -> Result<Self, String> {
+        let &(code, _, source) = constants::locale::LOCALES
+            .iter()
+            .find(|(c, _, _)| *c == code)
+            .ok_or(format!("{}{}", constants::strings::UNKNOWN_LOCALE_ERROR, code))?;
+
+        let table = source
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                line.split_once('=')
+                    .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+                    .ok_or(format!("{}{}", constants::strings::INVALID_VALUE_ERROR, line))
+            })
+            .collect::<Result<HashMap<String, String>, String>>()?;
+
+        Ok(Locale { code, table })
+    }
+
+    pub fn get_code(&self) -> &'static str {
+        self.code
+    }
+
+    pub fn translate<'a>(&'a self, text: &'a str) -> &'a str {
+        self.table.get(text).map_or(text, |t| t.as_str())
+    }
+}
@@ -1,18 +1,76 @@
-use crate::game::Game;
-
-mod asteroid;
-mod bullet;
-mod constants;
-mod game;
-mod particle;
-mod player;
-mod polygon;
-mod font;
-mod high_score;
-mod alien;
-mod black_hole;
+use asteroids::constants;
+use asteroids::game::Game;
+use asteroids::net::transport::{LossyTransport, Transport, UdpTransport};
+
+fn arg<T: std::str::FromStr>(args: &[String], name: &str, default: T) -> T {
+    args.iter()
+        .position(|a| a == name)
+        .and_then(|i| args.get(i + 1))
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(default)
+}
 
 fn main() {
+    let args: Vec<String> = std::env::args().collect();
+
+    if args.iter().any(|a| a == "--help" || a == "-h") {
+        println!("{}", constants::strings::USAGE_TEXT);
+        return;
+    }
+
     let mut game = Game::new().unwrap_or_else(|e| panic!("{}", e));
+
+    if let Some(i) = args.iter().position(|a| a == "--connect") {
+        let addr = args
+            .get(i + 1)
+            .unwrap_or_else(|| panic!("{}", constants::strings::MISSING_ADDRESS_ERROR));
+
+        game.connect(addr).unwrap_or_else(|e| panic!("{}", e));
+    }
+
+    if let Some(i) = args.iter().position(|a| a == "--spectate") {
+        let addr = args
+            .get(i + 1)
+            .unwrap_or_else(|| panic!("{}", constants::strings::MISSING_SPECTATE_ERROR));
+
+        game.spectate(addr).unwrap_or_else(|e| panic!("{}", e));
+    }
+
+    if let Some(i) = args.iter().position(|a| a == "--broadcast") {
+        let port = args
+            .get(i + 1)
+            .and_then(|p| p.parse().ok())
+            .unwrap_or_else(|| panic!("{}", constants::strings::MISSING_BROADCAST_ERROR));
+
+        game.broadcast(port).unwrap_or_else(|e| panic!("{}", e));
+    }
+
+    if let Some(i) = args.iter().position(|a| a == "--peer") {
+        let (Some(port), Some(remote)) = (
+            args.get(i + 1).and_then(|p| p.parse().ok()),
+            args.get(i + 2),
+        ) else {
+            panic!("{}", constants::strings::MISSING_PEER_ERROR);
+        };
+
+        let player = arg(&args, "--player", 0_usize);
+        let seed = arg(&args, "--seed", constants::rollback::DEFAULT_SEED);
+
+        let udp = UdpTransport::new(port, remote).unwrap_or_else(|e| panic!("{}", e));
+        let transport: Box<dyn Transport> = if args.iter().any(|a| a == "--loss" || a == "--latency") {
+            Box::new(LossyTransport::new(
+                Box::new(udp),
+                arg(&args, "--loss", 0.0),
+                arg(&args, "--latency", 0),
+                arg(&args, "--jitter", 0),
+                seed,
+            ))
+        } else {
+            Box::new(udp)
+        };
+
+        game.start_peer_to_peer(transport, player, seed);
+    }
+
     game.run();
 }
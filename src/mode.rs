@@ -0,0 +1,180 @@
+use crate::constants;
+use crate::session::Session;
+use crate::settings;
+use crate::tuning::Tuning;
+use std::fs;
+use std::io::ErrorKind;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Mode {
+    Classic,
+    TimeAttack,
+    Survival,
+    Zen,
+    Hardcore,
+}
+
+impl Mode {
+    pub const ALL: [Mode; constants::mode::COUNT] = [
+        Mode::Classic,
+        Mode::TimeAttack,
+        Mode::Survival,
+        Mode::Zen,
+        Mode::Hardcore,
+    ];
+
+    pub fn from_index(index: usize) -> Self {
+        Mode::ALL.get(index).copied().unwrap_or(Mode::Classic)
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Mode::ALL.into_iter().find(|m| m.get_key() == key)
+    }
+
+    pub fn get_index(self) -> usize {
+        self as usize
+    }
+
+    pub fn get_key(self) -> &'static str {
+        constants::mode::KEYS[self.get_index()]
+    }
+
+    pub fn get_name(self) -> &'static str {
+        constants::mode::NAMES[self.get_index()]
+    }
+
+    pub fn get_time_limit(self) -> Option<u64> {
+        match self {
+            Mode::TimeAttack => Some(constants::mode::TIME_ATTACK_MS),
+            _ => None,
+        }
+    }
+
+    pub fn can_die(self) -> bool {
+        self != Mode::Zen
+    }
+
+    pub fn apply(self, session: &mut Session, start_lives: u32) {
+        session.set_extra_lives(!matches!(self, Mode::Survival | Mode::Hardcore));
+        session.set_spawn_ramp(if self == Mode::Survival {
+            constants::mode::SURVIVAL_SPAWN_RAMP
+        } else {
+            0.0
+        });
+        self.apply_tuning(session.get_tuning_mut());
+
+        if let Some(player) = session.get_player_mut(0) {
+            player.set_lives(if self == Mode::Hardcore {
+                constants::mode::HARDCORE_LIVES
+            } else {
+                start_lives
+            });
+        }
+    }
+
+    pub fn apply_tuning(self, tuning: &mut Tuning) {
+        if self == Mode::Zen {
+            tuning.max_aliens = 0;
+            tuning.max_black_holes = 0;
+        }
+    }
+
+    pub fn get_remaining(self, session: &Session) -> Option<u64> {
+        self.get_time_limit()
+            .map(|limit| limit.saturating_sub(session.get_time()))
+    }
+
+    pub fn is_time_up(self, session: &Session) -> bool {
+        self.get_remaining(session) == Some(0)
+    }
+}
+
+#[derive(Default)]
+pub struct Leaderboards {
+    scores: [Vec<u64>; constants::mode::COUNT],
+}
+
+impl Leaderboards {
+    pub fn load() -> Result<Self, String> {
+        let mut leaderboards = Leaderboards::default();
+
+        let Some(path) = settings::get_config_path(constants::mode::FILE) else {
+            return Ok(leaderboards);
+        };
+
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(leaderboards),
+            Err(e) => return Err(e.to_string()),
+        };
+
+        contents
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .for_each(|line| {
+                let invalid = || format!("{}{}", constants::strings::INVALID_VALUE_ERROR, line);
+
+                let result = line.split_once('=').ok_or_else(invalid).and_then(|(key, value)| {
+                    let mode = Mode::from_key(key.trim())
+                        .ok_or(format!("{}{}", constants::strings::UNKNOWN_KEY_ERROR, key))?;
+
+                    leaderboards.scores[mode.get_index()] = value
+                        .split(',')
+                        .map(|s| s.trim())
+                        .filter(|s| !s.is_empty())
+                        .map(|s| s.parse::<u64>().map_err(|_| invalid()))
+                        .collect::<Result<Vec<u64>, String>>()?;
+
+                    Ok(())
+                });
+
+                if let Err(e) = result {
+                    println!("{}{}", constants::strings::LEADERBOARDS_ERROR, e);
+                }
+            });
+
+        Ok(leaderboards)
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = settings::get_config_path(constants::mode::FILE)
+            .ok_or(String::from(constants::strings::CONFIG_DIR_ERROR))?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+
+        let contents = Mode::ALL
+            .iter()
+            .map(|m| {
+                let scores = self.scores[m.get_index()]
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect::<Vec<String>>()
+                    .join(",");
+                format!("{}={}\n", m.get_key(), scores)
+            })
+            .collect::<String>();
+
+        fs::write(path, contents).map_err(|e| e.to_string())
+    }
+
+    pub fn get_scores(&self, mode: Mode) -> &[u64] {
+        &self.scores[mode.get_index()]
+    }
+
+    pub fn submit(&mut self, mode: Mode, score: u64) -> Option<usize> {
+        let scores = &mut self.scores[mode.get_index()];
+        let rank = scores.iter().position(|&s| score > s).unwrap_or(scores.len());
+
+        if rank >= constants::mode::LEADERBOARD_SIZE {
+            return None;
+        }
+
+        scores.insert(rank, score);
+        scores.truncate(constants::mode::LEADERBOARD_SIZE);
+
+        Some(rank)
+    }
+}
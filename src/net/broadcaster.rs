@@ -0,0 +1,151 @@
+use crate::constants;
+use crate::net::protocol::{Packet, delta_encode};
+use crate::session::Session;
+use std::collections::VecDeque;
+use std::io::ErrorKind;
+use std::net::{SocketAddr, UdpSocket};
+use std::time::{Duration, Instant};
+
+struct Viewer {
+    addr: SocketAddr,
+    ack: u32,
+    last_heard: Instant,
+}
+
+pub struct Broadcaster {
+    socket: UdpSocket,
+    viewers: Vec<Viewer>,
+
+    timer: f32,
+    snapshot_seq: u32,
+    history: VecDeque<(u32, Vec<u8>)>,
+}
+
+impl Broadcaster {
+    pub fn new(port: u16) -> Result<Self, String> {
+        let socket = UdpSocket::bind(("0.0.0.0", port)).map_err(|e| e.to_string())?;
+        socket.set_nonblocking(true).map_err(|e| e.to_string())?;
+
+        Ok(Broadcaster {
+            socket,
+            viewers: Vec::new(),
+
+            timer: 0.0,
+            snapshot_seq: 0,
+            history: VecDeque::new(),
+        })
+    }
+
+    pub fn update(&mut self, session: &Session, dt: f32) {
+        self.receive();
+
+        let timeout = Duration::from_millis(constants::net::TIMEOUT_MS);
+        self.viewers.retain(|v| {
+            let alive = v.last_heard.elapsed() <= timeout;
+            if !alive {
+                println!("{}{}", constants::strings::CLIENT_LEFT, v.addr);
+            }
+            alive
+        });
+
+        if self.viewers.is_empty() {
+            return;
+        }
+
+        let interval = (constants::net::TICK_MS * constants::net::SNAPSHOT_INTERVAL as u64) as f32 / 1000.0;
+        self.timer += dt;
+        if self.timer < interval {
+            return;
+        }
+        self.timer %= interval;
+
+        self.broadcast(session);
+    }
+
+    fn receive(&mut self) {
+        let mut buf = [0_u8; constants::net::MAX_PACKET_SIZE];
+
+        loop {
+            let (len, addr) = match self.socket.recv_from(&mut buf) {
+                Ok(res) => res,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(_) => continue,
+            };
+
+            let Some(packet) = Packet::read(&buf[..len]) else {
+                continue;
+            };
+
+            let index = self.viewers.iter().position(|v| v.addr == addr);
+
+            match (packet, index) {
+                (Packet::Spectate, None) => {
+                    println!("{}{}", constants::strings::SPECTATOR_JOINED, addr);
+                    self.viewers.push(Viewer {
+                        addr,
+                        ack: 0,
+                        last_heard: Instant::now(),
+                    });
+                    self.send(addr, &Packet::Welcome { player: u8::MAX });
+                }
+                (Packet::Spectate, Some(_)) => self.send(addr, &Packet::Welcome { player: u8::MAX }),
+                (Packet::Input { ack, .. }, Some(i)) => {
+                    let v = &mut self.viewers[i];
+                    v.last_heard = Instant::now();
+                    v.ack = v.ack.max(ack);
+                }
+                (Packet::Bye, Some(i)) => {
+                    println!("{}{}", constants::strings::CLIENT_LEFT, addr);
+                    self.viewers.remove(i);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn broadcast(&mut self, session: &Session) {
+        let mut snapshot = Vec::new();
+        session.write(&mut snapshot);
+
+        self.snapshot_seq += 1;
+        self.history.push_back((self.snapshot_seq, snapshot));
+        while self.history.len() > constants::net::SNAPSHOT_HISTORY {
+            self.history.pop_front();
+        }
+
+        let (seq, snapshot) = self.history.back().unwrap();
+
+        self.viewers.iter().for_each(|v| {
+            let (baseline, baseline_bytes) = self
+                .history
+                .iter()
+                .find(|(s, _)| *s == v.ack)
+                .map(|(s, b)| (*s, b.as_slice()))
+                .unwrap_or((0, &[]));
+
+            let packet = Packet::Snapshot {
+                seq: *seq,
+                baseline,
+                last_input: 0,
+                player: u8::MAX,
+                delta: delta_encode(snapshot, baseline_bytes),
+            };
+
+            let buf = packet.write();
+            if buf.len() > constants::net::MAX_PACKET_SIZE {
+                println!("{}{}", constants::strings::SNAPSHOT_SIZE_ERROR, buf.len());
+                return;
+            }
+
+            if let Err(e) = self.socket.send_to(&buf, v.addr) {
+                println!("{}{}", constants::strings::NETWORK_ERROR, e);
+            }
+        });
+    }
+
+    fn send(&self, addr: SocketAddr, packet: &Packet) {
+        if let Err(e) = self.socket.send_to(&packet.write(), addr) {
+            println!("{}{}", constants::strings::NETWORK_ERROR, e);
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use crate::constants;
+use crate::input::Input;
+use crate::net;
+use crate::net::bytes::Reader;
+use crate::net::protocol::{Packet, decode_snapshot};
+use crate::particle::Particle;
+use crate::player::Player;
+use crate::session::Session;
+use crate::tuning::Tuning;
+use sdl2::rect::Rect;
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+use std::collections::VecDeque;
+use std::io::ErrorKind;
+use std::net::UdpSocket;
+
+pub struct Client {
+    socket: UdpSocket,
+    player: usize,
+
+    session: Session,
+    predicted: Player,
+    particles: Vec<Particle>,
+
+    field_bounds: Rect,
+    accumulator: f32,
+    time: u64,
+
+    input_seq: u32,
+    pending_inputs: VecDeque<(u32, Input)>,
+
+    last_snapshot: u32,
+    history: VecDeque<(u32, Vec<u8>)>,
+}
+
+impl Client {
+    pub fn connect(addr: &str) -> Result<Self, String> {
+        let (socket, player) = net::handshake(addr, &Packet::Hello)?;
+        let player = player as usize;
+
+        let field_bounds = net::field_bounds();
+
+        Ok(Client {
+            socket,
+            player,
+
+            session: Session::new(field_bounds, 0, 0),
+            predicted: Player::new(
+                (field_bounds.width() / 2) as f32,
+                (field_bounds.height() / 2) as f32,
+            ),
+            particles: Vec::new(),
+
+            field_bounds,
+            accumulator: 0.0,
+            time: 0,
+
+            input_seq: 0,
+            pending_inputs: VecDeque::new(),
+
+            last_snapshot: 0,
+            history: VecDeque::new(),
+        })
+    }
+
+    pub fn update(&mut self, dt: f32, input: Input) {
+        self.receive();
+
+        let tick_dt = constants::net::TICK_MS as f32 / 1000.0;
+        self.accumulator = (self.accumulator + dt).min(constants::net::MAX_CATCH_UP);
+
+        while self.accumulator >= tick_dt {
+            self.accumulator -= tick_dt;
+            self.time += constants::net::TICK_MS;
+
+            self.input_seq += 1;
+            self.pending_inputs.push_back((self.input_seq, input));
+            while self.pending_inputs.len() > constants::net::MAX_PENDING_INPUTS {
+                self.pending_inputs.pop_front();
+            }
+
+            self.predicted.set_input(input);
+            self.predicted
+                .tick(tick_dt, self.field_bounds, &Tuning::default(), self.time, &mut rand::rng());
+            self.particles.append(&mut self.predicted.get_particles());
+
+            self.send_input();
+        }
+
+        self.particles.retain(|p| p.is_alive(self.time));
+        self.particles
+            .iter_mut()
+            .for_each(|p| p.tick(dt, self.field_bounds));
+    }
+
+    fn send_input(&mut self) {
+        let skip = self
+            .pending_inputs
+            .len()
+            .saturating_sub(constants::net::INPUT_REDUNDANCY);
+
+        let packet = Packet::Input {
+            ack: self.last_snapshot,
+            inputs: self.pending_inputs.iter().skip(skip).copied().collect(),
+        };
+
+        if let Err(e) = self.socket.send(&packet.write()) {
+            println!("{}{}", constants::strings::NETWORK_ERROR, e);
+        }
+    }
+
+    fn receive(&mut self) {
+        let mut buf = [0_u8; constants::net::MAX_PACKET_SIZE];
+
+        loop {
+            let len = match self.socket.recv(&mut buf) {
+                Ok(len) => len,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(_) => continue,
+            };
+
+            if let Some(Packet::Snapshot {
+                seq,
+                baseline,
+                last_input,
+                player,
+                delta,
+            }) = Packet::read(&buf[..len])
+            {
+                self.apply_snapshot(seq, baseline, last_input, player as usize, &delta);
+            }
+        }
+    }
+
+    fn apply_snapshot(&mut self, seq: u32, baseline: u32, last_input: u32, player: usize, delta: &[u8]) {
+        if seq <= self.last_snapshot {
+            return;
+        }
+
+        let Some(snapshot) = decode_snapshot(&self.history, baseline, delta) else {
+            return;
+        };
+
+        let Some(session) = Session::read(&mut Reader::new(&snapshot)) else {
+            return;
+        };
+
+        self.history.push_back((seq, snapshot));
+        while self.history.len() > constants::net::SNAPSHOT_HISTORY {
+            self.history.pop_front();
+        }
+
+        self.last_snapshot = seq;
+        self.session = session;
+        self.player = player;
+
+        self.pending_inputs.retain(|(s, _)| *s > last_input);
+
+        if let Some(p) = self.session.get_player(self.player) {
+            let tick_dt = constants::net::TICK_MS as f32 / 1000.0;
+            let mut predicted = p.clone();
+
+            self.pending_inputs.iter().for_each(|(_, input)| {
+                predicted.set_input(*input);
+                predicted.tick(
+                    tick_dt,
+                    self.field_bounds,
+                    &Tuning::default(),
+                    self.time,
+                    &mut rand::rng(),
+                );
+            });
+            predicted.get_particles();
+
+            self.predicted = predicted;
+        }
+    }
+
+    pub fn render(&self, canvas: &mut Canvas<Window>, screen_bounds: Rect) -> Result<(), String> {
+        let scale = net::field_scale(screen_bounds);
+        canvas.set_scale(scale, scale)?;
+
+        self.predicted.render(canvas, self.field_bounds)?;
+
+        self.session
+            .get_players()
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != self.player)
+            .try_for_each(|(_, p)| p.render(canvas, self.field_bounds))?;
+
+        self.particles.iter().try_for_each(|p| p.render(canvas))?;
+
+        self.session.render_field(canvas, self.field_bounds)?;
+
+        canvas.set_scale(1.0, 1.0)
+    }
+
+    pub fn get_player(&self) -> &Player {
+        &self.predicted
+    }
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        let _ = self.socket.send(&Packet::Bye.write());
+    }
+}
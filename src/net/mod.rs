@@ -0,0 +1,57 @@
+use crate::constants;
+use crate::net::protocol::Packet;
+use sdl2::rect::Rect;
+use std::net::UdpSocket;
+use std::time::Duration;
+
+pub mod broadcaster;
+pub mod bytes;
+pub mod client;
+pub mod protocol;
+pub mod rollback;
+pub mod server;
+pub mod spectator;
+pub mod transport;
+
+pub fn field_bounds() -> Rect {
+    Rect::new(
+        0,
+        0,
+        constants::net::FIELD_WIDTH,
+        constants::net::FIELD_HEIGHT,
+    )
+}
+
+pub fn field_scale(screen_bounds: Rect) -> f32 {
+    (screen_bounds.width() as f32 / constants::net::FIELD_WIDTH as f32)
+        .min(screen_bounds.height() as f32 / constants::net::FIELD_HEIGHT as f32)
+}
+
+pub fn handshake(addr: &str, request: &Packet) -> Result<(UdpSocket, u8), String> {
+    let socket = UdpSocket::bind("0.0.0.0:0").map_err(|e| e.to_string())?;
+    socket.connect(addr).map_err(|e| e.to_string())?;
+    socket
+        .set_read_timeout(Some(Duration::from_millis(
+            constants::net::CONNECT_RETRY_MS,
+        )))
+        .map_err(|e| e.to_string())?;
+
+    let mut buf = [0_u8; constants::net::MAX_PACKET_SIZE];
+    let mut player = None;
+
+    for _ in 0..constants::net::CONNECT_ATTEMPTS {
+        socket.send(&request.write()).map_err(|e| e.to_string())?;
+
+        if let Ok(len) = socket.recv(&mut buf)
+            && let Some(Packet::Welcome { player: p }) = Packet::read(&buf[..len])
+        {
+            player = Some(p);
+            break;
+        }
+    }
+
+    let player = player.ok_or(String::from(constants::strings::CONNECT_ERROR))?;
+    socket.set_nonblocking(true).map_err(|e| e.to_string())?;
+
+    Ok((socket, player))
+}
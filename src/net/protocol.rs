@@ -0,0 +1,272 @@
+use crate::constants;
+use crate::input::Input;
+use crate::net::bytes::Reader;
+use std::collections::VecDeque;
+
+pub enum Packet {
+    Hello,
+    Welcome {
+        player: u8,
+    },
+    Input {
+        ack: u32,
+        inputs: Vec<(u32, Input)>,
+    },
+    Snapshot {
+        seq: u32,
+        baseline: u32,
+        last_input: u32,
+        player: u8,
+        delta: Vec<u8>,
+    },
+    Bye,
+    Frames {
+        seed: u64,
+        start: u32,
+        inputs: Vec<Input>,
+        ack: u32,
+        hashes: Vec<(u32, u64)>,
+    },
+    Spectate,
+}
+
+impl Packet {
+    pub fn write(&self) -> Vec<u8> {
+        let mut buf = constants::net::MAGIC.to_le_bytes().to_vec();
+
+        match self {
+            Packet::Hello => buf.push(0),
+            Packet::Welcome { player } => {
+                buf.push(1);
+                buf.push(*player);
+            }
+            Packet::Input { ack, inputs } => {
+                buf.push(2);
+                buf.extend_from_slice(&ack.to_le_bytes());
+                buf.push(inputs.len() as u8);
+
+                inputs.iter().for_each(|(seq, input)| {
+                    buf.extend_from_slice(&seq.to_le_bytes());
+                    buf.push(input.to_byte());
+                });
+            }
+            Packet::Snapshot {
+                seq,
+                baseline,
+                last_input,
+                player,
+                delta,
+            } => {
+                buf.push(3);
+                buf.extend_from_slice(&seq.to_le_bytes());
+                buf.extend_from_slice(&baseline.to_le_bytes());
+                buf.extend_from_slice(&last_input.to_le_bytes());
+                buf.push(*player);
+                buf.extend_from_slice(delta);
+            }
+            Packet::Bye => buf.push(4),
+            Packet::Frames {
+                seed,
+                start,
+                inputs,
+                ack,
+                hashes,
+            } => {
+                buf.push(5);
+                buf.extend_from_slice(&seed.to_le_bytes());
+                buf.extend_from_slice(&start.to_le_bytes());
+                buf.push(inputs.len() as u8);
+                inputs.iter().for_each(|input| buf.push(input.to_byte()));
+                buf.extend_from_slice(&ack.to_le_bytes());
+                buf.push(hashes.len() as u8);
+
+                hashes.iter().for_each(|(frame, hash)| {
+                    buf.extend_from_slice(&frame.to_le_bytes());
+                    buf.extend_from_slice(&hash.to_le_bytes());
+                });
+            }
+            Packet::Spectate => buf.push(6),
+        }
+
+        buf
+    }
+
+    pub fn read(buf: &[u8]) -> Option<Packet> {
+        let mut reader = Reader::new(buf);
+
+        if reader.read_u16()? != constants::net::MAGIC {
+            return None;
+        }
+
+        match reader.read_u8()? {
+            0 => Some(Packet::Hello),
+            1 => Some(Packet::Welcome {
+                player: reader.read_u8()?,
+            }),
+            2 => {
+                let ack = reader.read_u32()?;
+                let inputs = (0..reader.read_u8()?)
+                    .map(|_| Some((reader.read_u32()?, Input::from_byte(reader.read_u8()?))))
+                    .collect::<Option<Vec<(u32, Input)>>>()?;
+
+                Some(Packet::Input { ack, inputs })
+            }
+            3 => Some(Packet::Snapshot {
+                seq: reader.read_u32()?,
+                baseline: reader.read_u32()?,
+                last_input: reader.read_u32()?,
+                player: reader.read_u8()?,
+                delta: reader.rest().to_vec(),
+            }),
+            4 => Some(Packet::Bye),
+            5 => {
+                let seed = reader.read_u64()?;
+                let start = reader.read_u32()?;
+                let inputs = (0..reader.read_u8()?)
+                    .map(|_| Some(Input::from_byte(reader.read_u8()?)))
+                    .collect::<Option<Vec<Input>>>()?;
+                let ack = reader.read_u32()?;
+                let hashes = (0..reader.read_u8()?)
+                    .map(|_| Some((reader.read_u32()?, reader.read_u64()?)))
+                    .collect::<Option<Vec<(u32, u64)>>>()?;
+
+                Some(Packet::Frames {
+                    seed,
+                    start,
+                    inputs,
+                    ack,
+                    hashes,
+                })
+            }
+            6 => Some(Packet::Spectate),
+            _ => None,
+        }
+    }
+}
+
+pub fn delta_encode(target: &[u8], baseline: &[u8]) -> Vec<u8> {
+    let xor = target
+        .iter()
+        .enumerate()
+        .map(|(i, b)| b ^ baseline.get(i).copied().unwrap_or(0))
+        .collect::<Vec<u8>>();
+
+    let mut out = (target.len() as u32).to_le_bytes().to_vec();
+    let mut i = 0;
+
+    while i < xor.len() {
+        let zeros = xor[i..]
+            .iter()
+            .take(u16::MAX as usize)
+            .take_while(|&&b| b == 0)
+            .count();
+        i += zeros;
+
+        let mut literals = 0;
+        while i + literals < xor.len() && literals < u16::MAX as usize {
+            let run = xor[i + literals..]
+                .iter()
+                .take(constants::net::MIN_ZERO_RUN)
+                .take_while(|&&b| b == 0)
+                .count();
+
+            if run == constants::net::MIN_ZERO_RUN || i + literals + run == xor.len() {
+                break;
+            }
+
+            literals += run.max(1);
+        }
+        let literals = literals.min(u16::MAX as usize);
+
+        out.extend_from_slice(&(zeros as u16).to_le_bytes());
+        out.extend_from_slice(&(literals as u16).to_le_bytes());
+        out.extend_from_slice(&xor[i..i + literals]);
+        i += literals;
+    }
+
+    out
+}
+
+pub fn delta_decode(delta: &[u8], baseline: &[u8]) -> Option<Vec<u8>> {
+    let mut reader = Reader::new(delta);
+    let len = reader.read_u32()? as usize;
+    let mut out: Vec<u8> = Vec::with_capacity(len);
+
+    while out.len() < len {
+        let zeros = reader.read_u16()? as usize;
+        let literal_len = reader.read_u16()? as usize;
+        let literals = reader.read_bytes(literal_len)?;
+
+        if (zeros == 0 && literals.is_empty()) || out.len() + zeros + literals.len() > len {
+            return None;
+        }
+
+        (0..zeros).for_each(|_| out.push(baseline.get(out.len()).copied().unwrap_or(0)));
+        literals
+            .iter()
+            .for_each(|b| out.push(b ^ baseline.get(out.len()).copied().unwrap_or(0)));
+    }
+
+    Some(out)
+}
+
+pub fn decode_snapshot(history: &VecDeque<(u32, Vec<u8>)>, baseline: u32, delta: &[u8]) -> Option<Vec<u8>> {
+    let baseline_bytes = if baseline == 0 {
+        &[][..]
+    } else {
+        history
+            .iter()
+            .find(|(s, _)| *s == baseline)
+            .map(|(_, bytes)| bytes.as_slice())?
+    };
+
+    delta_decode(delta, baseline_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delta_round_trip() {
+        let baseline = (0..200).map(|i| (i * 7) as u8).collect::<Vec<u8>>();
+        let mut target = baseline.clone();
+        target[10] ^= 0xFF;
+        target[11] = 3;
+        target[150] = 0;
+        target.extend_from_slice(&[1, 2, 3, 0, 0, 0, 0, 0, 4]);
+
+        let delta = delta_encode(&target, &baseline);
+
+        assert!(delta.len() < target.len());
+        assert_eq!(delta_decode(&delta, &baseline), Some(target.clone()));
+
+        let shorter = &target[..50];
+        assert_eq!(delta_decode(&delta_encode(shorter, &baseline), &baseline).as_deref(), Some(shorter));
+    }
+
+    #[test]
+    fn delta_without_baseline() {
+        let target = vec![0, 0, 0, 0, 0, 9, 8, 7, 0, 0, 1];
+
+        assert_eq!(delta_decode(&delta_encode(&target, &[]), &[]), Some(target.clone()));
+
+        let mut history = VecDeque::new();
+        history.push_back((1, vec![5_u8; target.len()]));
+
+        let delta = delta_encode(&target, &history[0].1);
+        assert_eq!(decode_snapshot(&history, 1, &delta), Some(target.clone()));
+        assert_eq!(decode_snapshot(&history, 2, &delta), None);
+
+        let full = delta_encode(&target, &[]);
+        assert_eq!(decode_snapshot(&history, 0, &full), Some(target));
+    }
+
+    #[test]
+    fn delta_rejects_truncated() {
+        let target = (0..100).collect::<Vec<u8>>();
+        let delta = delta_encode(&target, &[]);
+
+        assert_eq!(delta_decode(&delta[..delta.len() - 1], &[]), None);
+    }
+}
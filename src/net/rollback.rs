@@ -0,0 +1,350 @@
+use crate::constants;
+use crate::input::Input;
+use crate::net::protocol::Packet;
+use crate::net::transport::Transport;
+use crate::session::Session;
+use sdl2::rect::Rect;
+use std::collections::BTreeMap;
+
+pub struct RollbackPeer {
+    transport: Box<dyn Transport>,
+
+    local_player: usize,
+    remote_player: usize,
+    seed: u64,
+
+    field_bounds: Rect,
+    accumulator: f32,
+
+    frame: u32,
+    session: Session,
+    saved: BTreeMap<u32, Session>,
+
+    local_inputs: BTreeMap<u32, Input>,
+    remote_inputs: BTreeMap<u32, Input>,
+    used_remote_inputs: BTreeMap<u32, Input>,
+
+    remote_confirmed: u32,
+    remote_ack: u32,
+
+    hashes: BTreeMap<u32, u64>,
+    remote_hashes: BTreeMap<u32, u64>,
+    last_hashed: Option<u32>,
+
+    rollbacks: u32,
+    desync: Option<u32>,
+}
+
+impl RollbackPeer {
+    pub fn new(transport: Box<dyn Transport>, local_player: usize, seed: u64, field_bounds: Rect) -> Self {
+        let local_inputs = (0..constants::rollback::INPUT_DELAY)
+            .map(|f| (f, Input::default()))
+            .collect();
+
+        RollbackPeer {
+            transport,
+
+            local_player,
+            remote_player: 1 - local_player.min(1),
+            seed,
+
+            field_bounds,
+            accumulator: 0.0,
+
+            frame: 0,
+            session: Session::new(field_bounds, 2, seed),
+            saved: BTreeMap::new(),
+
+            local_inputs,
+            remote_inputs: BTreeMap::new(),
+            used_remote_inputs: BTreeMap::new(),
+
+            remote_confirmed: 0,
+            remote_ack: 0,
+
+            hashes: BTreeMap::new(),
+            remote_hashes: BTreeMap::new(),
+            last_hashed: None,
+
+            rollbacks: 0,
+            desync: None,
+        }
+    }
+
+    pub fn update(&mut self, dt: f32, input: Input) {
+        let tick_dt = constants::net::TICK_MS as f32 / 1000.0;
+        self.accumulator = (self.accumulator + dt).min(constants::net::MAX_CATCH_UP);
+
+        while self.accumulator >= tick_dt {
+            self.accumulator -= tick_dt;
+            self.step(input);
+        }
+    }
+
+    pub fn step(&mut self, input: Input) {
+        self.transport.advance(constants::net::TICK_MS);
+        self.receive();
+
+        if self.frame < self.remote_confirmed + constants::rollback::MAX_ROLLBACK {
+            self.local_inputs
+                .insert(self.frame + constants::rollback::INPUT_DELAY, input);
+            self.simulate_frame();
+        }
+
+        self.update_hashes();
+        self.send();
+        self.prune();
+    }
+
+    fn simulate_frame(&mut self) {
+        self.saved.insert(self.frame, self.session.clone());
+
+        let local = self
+            .local_inputs
+            .get(&self.frame)
+            .copied()
+            .unwrap_or_default();
+        let remote = self
+            .remote_inputs
+            .range(..=self.frame)
+            .next_back()
+            .map(|(_, input)| *input)
+            .unwrap_or_default();
+
+        self.used_remote_inputs.insert(self.frame, remote);
+
+        self.session.set_input(self.local_player, local);
+        self.session.set_input(self.remote_player, remote);
+
+        let dt = constants::net::TICK_MS as f32 / 1000.0;
+        self.session
+            .tick(dt, self.field_bounds)
+            .iter()
+            .for_each(|&i| self.session.respawn(i, self.field_bounds));
+
+        self.frame += 1;
+    }
+
+    fn rollback(&mut self, frame: u32) {
+        let Some(state) = self.saved.get(&frame) else {
+            return;
+        };
+
+        let target = self.frame;
+        self.session = state.clone();
+        self.frame = frame;
+        self.rollbacks += 1;
+
+        while self.frame < target {
+            self.simulate_frame();
+        }
+    }
+
+    fn receive(&mut self) {
+        let mut rollback_to: Option<u32> = None;
+
+        while let Some(data) = self.transport.receive() {
+            let Some(Packet::Frames {
+                seed,
+                start,
+                inputs,
+                ack,
+                hashes,
+            }) = Packet::read(&data)
+            else {
+                continue;
+            };
+
+            if seed != self.seed {
+                println!("{}{}", constants::strings::SEED_MISMATCH_ERROR, seed);
+                continue;
+            }
+
+            self.remote_ack = self.remote_ack.max(ack);
+
+            inputs.iter().enumerate().for_each(|(i, input)| {
+                let frame = start + i as u32;
+
+                if frame < self.remote_confirmed || self.remote_inputs.contains_key(&frame) {
+                    return;
+                }
+
+                self.remote_inputs.insert(frame, *input);
+
+                if self
+                    .used_remote_inputs
+                    .get(&frame)
+                    .is_some_and(|used| used != input)
+                {
+                    rollback_to = Some(rollback_to.map_or(frame, |f| f.min(frame)));
+                }
+            });
+
+            while self.remote_inputs.contains_key(&self.remote_confirmed) {
+                self.remote_confirmed += 1;
+            }
+
+            hashes.into_iter().for_each(|(frame, hash)| {
+                self.remote_hashes.insert(frame, hash);
+                self.check_hash(frame);
+            });
+        }
+
+        if let Some(frame) = rollback_to {
+            self.rollback(frame);
+        }
+    }
+
+    fn update_hashes(&mut self) {
+        let last_final = self.remote_confirmed.min(self.frame);
+        let first = self.last_hashed.map_or(0, |f| f + 1);
+
+        for frame in first..=last_final {
+            let hash = match self.saved.get(&frame) {
+                Some(state) => state.hash(),
+                None if frame == self.frame => self.session.hash(),
+                None => continue,
+            };
+
+            self.hashes.insert(frame, hash);
+            self.last_hashed = Some(frame);
+            self.check_hash(frame);
+        }
+    }
+
+    fn check_hash(&mut self, frame: u32) {
+        let (Some(local), Some(remote)) = (self.hashes.get(&frame), self.remote_hashes.get(&frame)) else {
+            return;
+        };
+
+        if local != remote && self.desync.is_none_or(|f| frame < f) {
+            println!("{}{}", constants::strings::DESYNC_TEXT, frame);
+            self.desync = Some(frame);
+        }
+    }
+
+    fn send(&mut self) {
+        let latest = self.frame + constants::rollback::INPUT_DELAY;
+
+        let inputs = self
+            .local_inputs
+            .range(self.remote_ack..latest)
+            .take(constants::rollback::MAX_INPUTS_PER_PACKET)
+            .map(|(_, input)| *input)
+            .collect::<Vec<Input>>();
+
+        let hashes = self
+            .hashes
+            .iter()
+            .rev()
+            .take(constants::rollback::HASH_REDUNDANCY)
+            .map(|(f, h)| (*f, *h))
+            .collect::<Vec<(u32, u64)>>();
+
+        let packet = Packet::Frames {
+            seed: self.seed,
+            start: self.remote_ack,
+            inputs,
+            ack: self.remote_confirmed,
+            hashes,
+        };
+
+        self.transport.send(&packet.write());
+    }
+
+    fn prune(&mut self) {
+        let oldest_state = self
+            .remote_confirmed
+            .min(self.last_hashed.map_or(0, |f| f + 1));
+        self.saved = self.saved.split_off(&oldest_state);
+
+        let oldest_local = self.remote_ack.min(self.remote_confirmed);
+        self.local_inputs = self.local_inputs.split_off(&oldest_local);
+        self.used_remote_inputs = self.used_remote_inputs.split_off(&self.remote_confirmed);
+
+        let oldest_input = self.remote_confirmed.saturating_sub(1);
+        self.remote_inputs = self.remote_inputs.split_off(&oldest_input);
+
+        let oldest_hash = self
+            .last_hashed
+            .unwrap_or(0)
+            .saturating_sub(constants::rollback::HASH_HISTORY);
+        self.hashes = self.hashes.split_off(&oldest_hash);
+        self.remote_hashes = self.remote_hashes.split_off(&oldest_hash);
+    }
+
+    pub fn get_session(&self) -> &Session {
+        &self.session
+    }
+
+    pub fn get_local_player(&self) -> usize {
+        self.local_player
+    }
+
+    pub fn get_frame(&self) -> u32 {
+        self.frame
+    }
+
+    pub fn get_last_verified_frame(&self) -> Option<u32> {
+        self.hashes
+            .keys()
+            .rev()
+            .find(|f| self.remote_hashes.contains_key(f))
+            .copied()
+    }
+
+    pub fn get_rollbacks(&self) -> u32 {
+        self.rollbacks
+    }
+
+    pub fn get_desync(&self) -> Option<u32> {
+        self.desync
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::net;
+    use crate::net::transport::{LocalTransport, LossyTransport};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn lossy_peers_converge() {
+        let seed = constants::rollback::DEFAULT_SEED;
+
+        let (a, b) = LocalTransport::pair();
+        let a = LossyTransport::new(Box::new(a), 0.1, 60, 20, seed);
+        let b = LossyTransport::new(Box::new(b), 0.1, 60, 20, seed + 1);
+
+        let mut peers = [
+            RollbackPeer::new(Box::new(a), 0, seed, net::field_bounds()),
+            RollbackPeer::new(Box::new(b), 1, seed, net::field_bounds()),
+        ];
+
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut inputs = [Input::default(); 2];
+
+        (0..1200).for_each(|_| {
+            inputs = inputs.map(|last| {
+                if rng.random_range(0..10) == 0 {
+                    Input::from_byte(rng.random_range(0..16))
+                } else {
+                    last
+                }
+            });
+            peers.iter_mut().zip(inputs).for_each(|(peer, input)| peer.step(input));
+        });
+
+        (0..constants::rollback::TEST_SETTLE_FRAMES).for_each(|_| {
+            peers.iter_mut().zip(inputs).for_each(|(peer, input)| peer.step(input));
+        });
+
+        assert!(peers.iter().all(|p| p.get_desync().is_none()));
+        assert!(peers.iter().all(|p| p.get_last_verified_frame().is_some()));
+        assert!(peers.iter().any(|p| p.get_rollbacks() > 0));
+        assert_eq!(peers[0].get_frame(), peers[1].get_frame());
+        assert_eq!(peers[0].get_session().hash(), peers[1].get_session().hash());
+    }
+}
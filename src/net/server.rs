@@ -0,0 +1,261 @@
+use crate::constants;
+use crate::input::Input;
+use crate::net;
+use crate::net::protocol::{Packet, delta_encode};
+use crate::session::Session;
+use rand::Rng;
+use sdl2::rect::Rect;
+use std::collections::VecDeque;
+use std::io::ErrorKind;
+use std::net::{SocketAddr, UdpSocket};
+use std::thread;
+use std::time::{Duration, Instant};
+
+struct Connection {
+    addr: SocketAddr,
+    player: Option<usize>,
+
+    inputs: VecDeque<(u32, Input)>,
+    input: Input,
+    last_queued: u32,
+    last_input: u32,
+
+    ack: u32,
+    last_heard: Instant,
+}
+
+pub struct Server {
+    socket: UdpSocket,
+    session: Session,
+    connections: Vec<Connection>,
+
+    field_bounds: Rect,
+
+    ticks: u32,
+    snapshot_seq: u32,
+    history: VecDeque<(u32, Vec<u8>)>,
+}
+
+impl Server {
+    pub fn new(port: u16, versus: bool) -> Result<Self, String> {
+        let socket = UdpSocket::bind(("0.0.0.0", port)).map_err(|e| e.to_string())?;
+        socket.set_nonblocking(true).map_err(|e| e.to_string())?;
+
+        let field_bounds = net::field_bounds();
+
+        let mut session = Session::new(field_bounds, 0, rand::rng().random());
+        session.set_friendly_fire(versus);
+
+        Ok(Server {
+            socket,
+            session,
+            connections: Vec::new(),
+
+            field_bounds,
+
+            ticks: 0,
+            snapshot_seq: 0,
+            history: VecDeque::new(),
+        })
+    }
+
+    pub fn run(&mut self) {
+        let tick_length = Duration::from_millis(constants::net::TICK_MS);
+
+        loop {
+            let start = Instant::now();
+
+            self.tick();
+
+            if let Some(remaining) = tick_length.checked_sub(start.elapsed()) {
+                thread::sleep(remaining);
+            }
+        }
+    }
+
+    pub fn tick(&mut self) {
+        self.receive();
+        self.drop_timed_out();
+
+        self.connections.iter_mut().for_each(|c| {
+            while c.inputs.len() > constants::net::MAX_INPUT_QUEUE {
+                c.inputs.pop_front();
+            }
+
+            if let Some((seq, input)) = c.inputs.pop_front() {
+                c.last_input = seq;
+                c.input = input;
+            }
+
+            if let Some(player) = c.player {
+                self.session.set_input(player, c.input);
+            }
+        });
+
+        if self.connections.is_empty() {
+            return;
+        }
+
+        if !self.session.get_players().is_empty() {
+            let dt = constants::net::TICK_MS as f32 / 1000.0;
+            self.session
+                .tick(dt, self.field_bounds)
+                .iter()
+                .for_each(|&i| self.session.respawn(i, self.field_bounds));
+        }
+
+        self.ticks += 1;
+        if self.ticks.is_multiple_of(constants::net::SNAPSHOT_INTERVAL) {
+            self.broadcast();
+        }
+    }
+
+    fn receive(&mut self) {
+        let mut buf = [0_u8; constants::net::MAX_PACKET_SIZE];
+
+        loop {
+            let (len, addr) = match self.socket.recv_from(&mut buf) {
+                Ok(res) => res,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(_) => continue,
+            };
+
+            let Some(packet) = Packet::read(&buf[..len]) else {
+                continue;
+            };
+
+            let index = self.connections.iter().position(|c| c.addr == addr);
+
+            match (packet, index) {
+                (Packet::Hello, None) => {
+                    if self.session.get_players().len() >= u8::MAX as usize {
+                        continue;
+                    }
+
+                    let player = self.session.add_player(self.field_bounds);
+                    self.add_connection(addr, Some(player));
+                }
+                (Packet::Spectate, None) => {
+                    println!("{}{}", constants::strings::SPECTATOR_JOINED, addr);
+                    self.add_connection(addr, None);
+                }
+                (Packet::Hello | Packet::Spectate, Some(i)) => {
+                    let player = self.connections[i].player.map_or(u8::MAX, |p| p as u8);
+                    self.send(addr, &Packet::Welcome { player });
+                }
+                (Packet::Input { ack, inputs }, Some(i)) => {
+                    let c = &mut self.connections[i];
+                    c.last_heard = Instant::now();
+                    c.ack = c.ack.max(ack);
+
+                    inputs.into_iter().for_each(|(seq, input)| {
+                        if seq > c.last_queued {
+                            c.last_queued = seq;
+                            c.inputs.push_back((seq, input));
+                        }
+                    });
+                }
+                (Packet::Bye, Some(i)) => self.remove_connection(i),
+                _ => {}
+            }
+        }
+    }
+
+    fn add_connection(&mut self, addr: SocketAddr, player: Option<usize>) {
+        self.connections.push(Connection {
+            addr,
+            player,
+
+            inputs: VecDeque::new(),
+            input: Input::default(),
+            last_queued: 0,
+            last_input: 0,
+
+            ack: 0,
+            last_heard: Instant::now(),
+        });
+
+        if player.is_some() {
+            println!("{}{}", constants::strings::CLIENT_JOINED, addr);
+        }
+
+        let player = player.map_or(u8::MAX, |p| p as u8);
+        self.send(addr, &Packet::Welcome { player });
+    }
+
+    fn drop_timed_out(&mut self) {
+        let timeout = Duration::from_millis(constants::net::TIMEOUT_MS);
+
+        while let Some(i) = self
+            .connections
+            .iter()
+            .position(|c| c.last_heard.elapsed() > timeout)
+        {
+            self.remove_connection(i);
+        }
+    }
+
+    fn remove_connection(&mut self, index: usize) {
+        let connection = self.connections.remove(index);
+
+        if let Some(player) = connection.player {
+            self.session.remove_player(player);
+
+            self.connections.iter_mut().for_each(|c| {
+                if let Some(p) = &mut c.player
+                    && *p > player
+                {
+                    *p -= 1;
+                }
+            });
+        }
+
+        println!("{}{}", constants::strings::CLIENT_LEFT, connection.addr);
+    }
+
+    fn broadcast(&mut self) {
+        let mut snapshot = Vec::new();
+        self.session.write(&mut snapshot);
+
+        self.snapshot_seq += 1;
+        self.history.push_back((self.snapshot_seq, snapshot));
+        while self.history.len() > constants::net::SNAPSHOT_HISTORY {
+            self.history.pop_front();
+        }
+
+        let (seq, snapshot) = self.history.back().unwrap();
+
+        self.connections.iter().for_each(|c| {
+            let (baseline, baseline_bytes) = self
+                .history
+                .iter()
+                .find(|(s, _)| *s == c.ack)
+                .map(|(s, b)| (*s, b.as_slice()))
+                .unwrap_or((0, &[]));
+
+            let packet = Packet::Snapshot {
+                seq: *seq,
+                baseline,
+                last_input: c.last_input,
+                player: c.player.map_or(u8::MAX, |p| p as u8),
+                delta: delta_encode(snapshot, baseline_bytes),
+            };
+
+            let buf = packet.write();
+            if buf.len() > constants::net::MAX_PACKET_SIZE {
+                println!("{}{}", constants::strings::SNAPSHOT_SIZE_ERROR, buf.len());
+                return;
+            }
+
+            if let Err(e) = self.socket.send_to(&buf, c.addr) {
+                println!("{}{}", constants::strings::NETWORK_ERROR, e);
+            }
+        });
+    }
+
+    fn send(&self, addr: SocketAddr, packet: &Packet) {
+        if let Err(e) = self.socket.send_to(&packet.write(), addr) {
+            println!("{}{}", constants::strings::NETWORK_ERROR, e);
+        }
+    }
+}
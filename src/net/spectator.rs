@@ -0,0 +1,337 @@
+use crate::alien::ShootingType;
+use crate::constants;
+use crate::font;
+use crate::locale::Locale;
+use crate::net;
+use crate::net::bytes::Reader;
+use crate::net::protocol::{Packet, decode_snapshot};
+use crate::session::Session;
+use crate::ui::{self, Align};
+use sdl2::keyboard::Keycode;
+use sdl2::rect::Rect;
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+use std::collections::VecDeque;
+use std::io::ErrorKind;
+use std::net::UdpSocket;
+
+#[derive(Default)]
+struct Camera {
+    left: bool,
+    right: bool,
+    up: bool,
+    down: bool,
+    zoom_in: bool,
+    zoom_out: bool,
+}
+
+pub struct Spectator {
+    socket: UdpSocket,
+    session: Session,
+
+    field_bounds: Rect,
+
+    camera: Camera,
+    x: f32,
+    y: f32,
+    zoom: f32,
+    follow: Option<usize>,
+
+    show_labels: bool,
+    show_scores: bool,
+
+    last_snapshot: u32,
+    history: VecDeque<(u32, Vec<u8>)>,
+}
+
+impl Spectator {
+    pub fn connect(addr: &str) -> Result<Self, String> {
+        let (socket, _) = net::handshake(addr, &Packet::Spectate)?;
+        let field_bounds = net::field_bounds();
+
+        Ok(Spectator {
+            socket,
+            session: Session::new(field_bounds, 0, 0),
+
+            field_bounds,
+
+            camera: Camera::default(),
+            x: 0.0,
+            y: 0.0,
+            zoom: 1.0,
+            follow: None,
+
+            show_labels: true,
+            show_scores: true,
+
+            last_snapshot: 0,
+            history: VecDeque::new(),
+        })
+    }
+
+    pub fn update(&mut self, dt: f32, screen_bounds: Rect) {
+        self.receive();
+
+        let packet = Packet::Input {
+            ack: self.last_snapshot,
+            inputs: Vec::new(),
+        };
+
+        if let Err(e) = self.socket.send(&packet.write()) {
+            println!("{}{}", constants::strings::NETWORK_ERROR, e);
+        }
+
+        let pan = constants::spectator::PAN_SPEED * dt / self.zoom;
+        if self.camera.left {
+            self.x -= pan;
+        }
+        if self.camera.right {
+            self.x += pan;
+        }
+        if self.camera.up {
+            self.y -= pan;
+        }
+        if self.camera.down {
+            self.y += pan;
+        }
+        if self.camera.left || self.camera.right || self.camera.up || self.camera.down {
+            self.follow = None;
+        }
+
+        let (view_w, view_h) = self.get_view_size(screen_bounds);
+        let (centre_x, centre_y) = (self.x + view_w / 2.0, self.y + view_h / 2.0);
+
+        if self.camera.zoom_in {
+            self.zoom *= constants::spectator::ZOOM_SPEED.powf(dt);
+        }
+        if self.camera.zoom_out {
+            self.zoom /= constants::spectator::ZOOM_SPEED.powf(dt);
+        }
+        self.zoom = self
+            .zoom
+            .clamp(constants::spectator::MIN_ZOOM, constants::spectator::MAX_ZOOM);
+
+        let (view_w, view_h) = self.get_view_size(screen_bounds);
+
+        match self.follow.and_then(|i| self.session.get_player(i)) {
+            Some(p) => {
+                self.x = p.get_x() - view_w / 2.0;
+                self.y = p.get_y() - view_h / 2.0;
+            }
+            None => {
+                self.x = centre_x - view_w / 2.0;
+                self.y = centre_y - view_h / 2.0;
+            }
+        }
+
+        self.x = self
+            .x
+            .clamp(0.0, (self.field_bounds.width() as f32 - view_w).max(0.0));
+        self.y = self
+            .y
+            .clamp(0.0, (self.field_bounds.height() as f32 - view_h).max(0.0));
+    }
+
+    pub fn handle_key_event(&mut self, key: Keycode, pressed: bool) {
+        match key {
+            Keycode::A | Keycode::LEFT => self.camera.left = pressed,
+            Keycode::D | Keycode::RIGHT => self.camera.right = pressed,
+            Keycode::W | Keycode::UP => self.camera.up = pressed,
+            Keycode::S | Keycode::DOWN => self.camera.down = pressed,
+            Keycode::E | Keycode::EQUALS => self.camera.zoom_in = pressed,
+            Keycode::Q | Keycode::MINUS => self.camera.zoom_out = pressed,
+            _ => {}
+        }
+
+        if !pressed {
+            return;
+        }
+
+        match key {
+            Keycode::TAB => {
+                let players = self.session.get_players().len();
+                self.follow = match self.follow {
+                    None if players > 0 => Some(0),
+                    Some(i) if i + 1 < players => Some(i + 1),
+                    _ => None,
+                };
+            }
+            Keycode::R => {
+                self.follow = None;
+                self.zoom = 1.0;
+                self.x = 0.0;
+                self.y = 0.0;
+            }
+            Keycode::L => self.show_labels = !self.show_labels,
+            Keycode::O => self.show_scores = !self.show_scores,
+            _ => {}
+        }
+    }
+
+    fn receive(&mut self) {
+        let mut buf = [0_u8; constants::net::MAX_PACKET_SIZE];
+
+        loop {
+            let len = match self.socket.recv(&mut buf) {
+                Ok(len) => len,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(_) => continue,
+            };
+
+            let Some(Packet::Snapshot {
+                seq, baseline, delta, ..
+            }) = Packet::read(&buf[..len])
+            else {
+                continue;
+            };
+
+            if seq <= self.last_snapshot {
+                continue;
+            }
+
+            let Some(snapshot) = decode_snapshot(&self.history, baseline, &delta) else {
+                continue;
+            };
+
+            let Some(session) = Session::read(&mut Reader::new(&snapshot)) else {
+                continue;
+            };
+
+            self.history.push_back((seq, snapshot));
+            while self.history.len() > constants::net::SNAPSHOT_HISTORY {
+                self.history.pop_front();
+            }
+
+            self.last_snapshot = seq;
+            self.session = session;
+        }
+    }
+
+    pub fn render(&self, canvas: &mut Canvas<Window>, screen_bounds: Rect, locale: &Locale) -> Result<(), String> {
+        let scale = self.get_scale(screen_bounds);
+        canvas.set_scale(scale, scale)?;
+        canvas.set_viewport(Rect::new(
+            -self.x as i32,
+            -self.y as i32,
+            self.field_bounds.width(),
+            self.field_bounds.height(),
+        ));
+
+        self.session.render(canvas, self.field_bounds)?;
+
+        canvas.set_viewport(None);
+        canvas.set_scale(1.0, 1.0)?;
+
+        if self.show_labels {
+            self.render_labels(canvas, screen_bounds, locale)?;
+        }
+
+        if self.show_scores {
+            self.render_scores(canvas, locale)?;
+        }
+
+        Ok(())
+    }
+
+    fn render_labels(&self, canvas: &mut Canvas<Window>, screen_bounds: Rect, locale: &Locale) -> Result<(), String> {
+        let mut labels: Vec<(String, f32, f32)> = Vec::new();
+
+        self.session
+            .get_players()
+            .iter()
+            .enumerate()
+            .for_each(|(i, p)| {
+                labels.push((
+                    format!("{} {}", locale.translate(constants::strings::PLAYER_TEXT), i + 1),
+                    p.get_x(),
+                    p.get_y(),
+                ))
+            });
+
+        self.session.get_asteroids().iter().for_each(|a| {
+            labels.push((
+                (a.get_radius() as u32).to_string(),
+                a.get_x(),
+                a.get_y(),
+            ))
+        });
+
+        self.session.get_aliens().iter().for_each(|a| {
+            let label = match a.get_type() {
+                ShootingType::Random => constants::strings::RANDOM_LABEL,
+                ShootingType::Current => constants::strings::CURRENT_LABEL,
+                ShootingType::Future => constants::strings::FUTURE_LABEL,
+            };
+            labels.push((String::from(locale.translate(label)), a.get_x(), a.get_y()))
+        });
+
+        self.session.get_black_holes().iter().for_each(|b| {
+            labels.push((
+                String::from(locale.translate(constants::strings::BLACK_HOLE_LABEL)),
+                b.get_x(),
+                b.get_y(),
+            ))
+        });
+
+        let scale = self.get_scale(screen_bounds);
+
+        labels.iter().try_for_each(|(text, x, y)| {
+            ui::render_text(
+                text.as_str(),
+                ((x - self.x) * scale) as i32,
+                ((y - self.y) * scale) as i32
+                    - constants::spectator::LABEL_OFFSET
+                    - 2 * constants::font::FONT_SIZE as i32,
+                Align::Center,
+                1.0,
+                canvas,
+            )
+        })
+    }
+
+    fn render_scores(&self, canvas: &mut Canvas<Window>, locale: &Locale) -> Result<(), String> {
+        font::render_text(locale.translate(constants::strings::SPECTATING_TEXT), 10, 10, canvas)?;
+
+        let line_height = (2 * constants::font::FONT_SIZE + constants::font::MARGIN) as i32;
+
+        self.session
+            .get_players()
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, p)| {
+                let text = format!(
+                    "{} {}  {}  x{}",
+                    locale.translate(constants::strings::PLAYER_TEXT),
+                    i + 1,
+                    p.get_score(),
+                    p.get_lives()
+                );
+
+                font::render_text(
+                    text.as_str(),
+                    10,
+                    10 + (i as i32 + 1) * line_height,
+                    canvas,
+                )
+            })
+    }
+
+    fn get_scale(&self, screen_bounds: Rect) -> f32 {
+        net::field_scale(screen_bounds) * self.zoom
+    }
+
+    fn get_view_size(&self, screen_bounds: Rect) -> (f32, f32) {
+        let scale = self.get_scale(screen_bounds);
+
+        (
+            screen_bounds.width() as f32 / scale,
+            screen_bounds.height() as f32 / scale,
+        )
+    }
+}
+
+impl Drop for Spectator {
+    fn drop(&mut self) {
+        let _ = self.socket.send(&Packet::Bye.write());
+    }
+}
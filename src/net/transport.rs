@@ -0,0 +1,136 @@
+use crate::constants;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io::ErrorKind;
+use std::net::UdpSocket;
+use std::rc::Rc;
+
+pub trait Transport {
+    fn send(&mut self, data: &[u8]);
+    fn receive(&mut self) -> Option<Vec<u8>>;
+    fn advance(&mut self, _ms: u64) {}
+}
+
+pub struct UdpTransport {
+    socket: UdpSocket,
+}
+
+impl UdpTransport {
+    pub fn new(local_port: u16, remote: &str) -> Result<Self, String> {
+        let socket = UdpSocket::bind(("0.0.0.0", local_port)).map_err(|e| e.to_string())?;
+        socket.connect(remote).map_err(|e| e.to_string())?;
+        socket.set_nonblocking(true).map_err(|e| e.to_string())?;
+
+        Ok(UdpTransport { socket })
+    }
+}
+
+impl Transport for UdpTransport {
+    fn send(&mut self, data: &[u8]) {
+        let _ = self.socket.send(data);
+    }
+
+    fn receive(&mut self) -> Option<Vec<u8>> {
+        let mut buf = vec![0_u8; constants::net::MAX_PACKET_SIZE];
+
+        loop {
+            match self.socket.recv(&mut buf) {
+                Ok(len) => {
+                    buf.truncate(len);
+                    return Some(buf);
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return None,
+                Err(_) => continue,
+            }
+        }
+    }
+}
+
+pub struct LocalTransport {
+    outgoing: Rc<RefCell<VecDeque<Vec<u8>>>>,
+    incoming: Rc<RefCell<VecDeque<Vec<u8>>>>,
+}
+
+impl LocalTransport {
+    pub fn pair() -> (LocalTransport, LocalTransport) {
+        let a = Rc::new(RefCell::new(VecDeque::new()));
+        let b = Rc::new(RefCell::new(VecDeque::new()));
+
+        (
+            LocalTransport {
+                outgoing: a.clone(),
+                incoming: b.clone(),
+            },
+            LocalTransport {
+                outgoing: b,
+                incoming: a,
+            },
+        )
+    }
+}
+
+impl Transport for LocalTransport {
+    fn send(&mut self, data: &[u8]) {
+        self.outgoing.borrow_mut().push_back(data.to_vec());
+    }
+
+    fn receive(&mut self) -> Option<Vec<u8>> {
+        self.incoming.borrow_mut().pop_front()
+    }
+}
+
+pub struct LossyTransport {
+    inner: Box<dyn Transport>,
+
+    loss: f32,
+    latency: u64,
+    jitter: u64,
+
+    now: u64,
+    queue: Vec<(u64, Vec<u8>)>,
+    rng: StdRng,
+}
+
+impl LossyTransport {
+    pub fn new(inner: Box<dyn Transport>, loss: f32, latency: u64, jitter: u64, seed: u64) -> Self {
+        LossyTransport {
+            inner,
+
+            loss,
+            latency,
+            jitter,
+
+            now: 0,
+            queue: Vec::new(),
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Transport for LossyTransport {
+    fn send(&mut self, data: &[u8]) {
+        if self.rng.random_range(0.0..1.0) < self.loss {
+            return;
+        }
+
+        let delay = self.latency + self.rng.random_range(0..=self.jitter);
+        self.queue.push((self.now + delay, data.to_vec()));
+    }
+
+    fn receive(&mut self) -> Option<Vec<u8>> {
+        self.inner.receive()
+    }
+
+    fn advance(&mut self, ms: u64) {
+        self.now += ms;
+
+        let now = self.now;
+        let (due, waiting): (Vec<_>, Vec<_>) = self.queue.drain(..).partition(|(t, _)| *t <= now);
+        self.queue = waiting;
+
+        due.iter().for_each(|(_, data)| self.inner.send(data));
+        self.inner.advance(ms);
+    }
+}
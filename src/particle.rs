@@ -4,7 +4,6 @@ use rand::Rng;
 use sdl2::pixels::Color;
 use sdl2::rect::{Point, Rect};
 use sdl2::render::Canvas;
-use sdl2::sys::SDL_GetTicks64;
 use sdl2::video::Window;
 
 #[derive(Copy, Clone)]
@@ -19,27 +18,27 @@ pub struct Particle {
 }
 
 impl Particle {
-    pub fn new(x: f32, y: f32, vx: f32, vy: f32) -> Self {
+    pub fn new(x: f32, y: f32, vx: f32, vy: f32, now: u64, rng: &mut impl Rng) -> Self {
         Particle {
             x,
             y,
             vx,
             vy,
-            death: unsafe { SDL_GetTicks64() }
-                + rand::rng().random_range(
+            death: now
+                + rng.random_range(
                     constants::particle::MIN_LIFESPAN..constants::particle::MAX_LIFESPAN,
                 ),
         }
     }
     
-    pub fn generate_explosion_particles(x: f32, y: f32) -> Vec<Particle> {
-        let num_particles = rand::rng().random_range(constants::particle::explosion::COUNT_RANGE);
+    pub fn generate_explosion_particles(x: f32, y: f32, now: u64, rng: &mut impl Rng) -> Vec<Particle> {
+        let num_particles = rng.random_range(constants::particle::explosion::COUNT_RANGE);
 
         (0..num_particles).map(|_| {
-            let angle = rand::rng().random_range(0.0..(PI * 2.0));
-            let vel = rand::rng().random_range(constants::particle::explosion::VEL_RANGE);
-            
-            Particle::new(x, y, vel * angle.cos(), vel * angle.sin())            
+            let angle = rng.random_range(0.0..(PI * 2.0));
+            let vel = rng.random_range(constants::particle::explosion::VEL_RANGE);
+
+            Particle::new(x, y, vel * angle.cos(), vel * angle.sin(), now, rng)
         }).collect::<Vec<Particle>>()
     }
 
@@ -71,7 +70,7 @@ impl Particle {
         Ok(())
     }
 
-    pub fn is_alive(&self) -> bool {
-        self.death >= unsafe { SDL_GetTicks64() }
+    pub fn is_alive(&self, now: u64) -> bool {
+        self.death >= now
     }
 }
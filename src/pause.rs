@@ -0,0 +1,88 @@
+use crate::constants;
+use crate::input::MenuInput;
+use crate::locale::Locale;
+use crate::ui::{Menu, MenuEvent, Widget};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::{BlendMode, Canvas};
+use sdl2::video::Window;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum PauseAction {
+    Resume,
+    Restart,
+    Settings,
+    QuitToMenu,
+    Exit,
+}
+
+pub struct PauseMenu {
+    open: bool,
+    menu: Menu,
+}
+
+impl Default for PauseMenu {
+    fn default() -> Self {
+        PauseMenu {
+            open: false,
+            menu: Menu::new(
+                constants::strings::PAUSED_TEXT,
+                constants::pause::ITEMS
+                    .iter()
+                    .map(|s| Widget::Button(s.to_string()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl PauseMenu {
+    pub fn new() -> Self {
+        PauseMenu::default()
+    }
+
+    pub fn open(&mut self) {
+        self.open = true;
+        self.menu.set_focus(0);
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn set_scale(&mut self, scale: f32) {
+        self.menu.set_scale(scale);
+    }
+
+    pub fn handle_input(&mut self, input: MenuInput) -> Option<PauseAction> {
+        let action = match self.menu.handle_input(input)? {
+            MenuEvent::Activated(0) | MenuEvent::Back => PauseAction::Resume,
+            MenuEvent::Activated(1) => PauseAction::Restart,
+            MenuEvent::Activated(2) => return Some(PauseAction::Settings),
+            MenuEvent::Activated(3) => PauseAction::QuitToMenu,
+            MenuEvent::Activated(_) => PauseAction::Exit,
+            MenuEvent::Changed(_) => return None,
+        };
+
+        self.close();
+
+        Some(action)
+    }
+
+    pub fn render(&self, canvas: &mut Canvas<Window>, screen_bounds: Rect, locale: &Locale) -> Result<(), String> {
+        if !self.open {
+            return Ok(());
+        }
+
+        canvas.set_blend_mode(BlendMode::Blend);
+        canvas.set_draw_color(Color::RGBA(0, 0, 0, constants::pause::DIM_ALPHA));
+        canvas.fill_rect(screen_bounds)?;
+        canvas.set_blend_mode(BlendMode::None);
+
+        self.menu.render(canvas, screen_bounds, locale)
+    }
+}
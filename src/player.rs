@@ -1,15 +1,17 @@
 use crate::bullet::Bullet;
 use crate::constants;
+use crate::input::Input;
+use crate::net::bytes::Reader;
 use crate::particle::Particle;
+use crate::tuning::Tuning;
 use rand::Rng;
-use sdl2::keyboard::Keycode;
 use sdl2::pixels::Color;
 use sdl2::rect::{Point, Rect};
 use sdl2::render::Canvas;
-use sdl2::sys::SDL_GetTicks64;
 use sdl2::video::Window;
 use std::f32::consts::PI;
 
+#[derive(Clone)]
 pub struct Player {
     x: f32,
     y: f32,
@@ -19,9 +21,13 @@ pub struct Player {
 
     angle: f32,
 
-    left: bool,
-    right: bool,
-    up: bool,
+    input: Input,
+    fire_held: bool,
+    next_autofire: u64,
+
+    lives: u32,
+    next_life_points: u64,
+    score: u64,
 
     particles_to_spawn: Vec<Particle>,
 
@@ -39,16 +45,20 @@ impl Player {
 
             angle: -PI / 2.0,
 
-            left: false,
-            right: false,
-            up: false,
+            input: Input::default(),
+            fire_held: false,
+            next_autofire: 0,
+
+            lives: constants::player::START_LIVES,
+            next_life_points: constants::player::POINTS_PER_LIFE,
+            score: 0,
 
             particles_to_spawn: Vec::new(),
             last_thrust_particle: 0,
         }
     }
 
-    pub fn tick(&mut self, dt: f32, screen_bounds: Rect) {
+    pub fn tick(&mut self, dt: f32, screen_bounds: Rect, tuning: &Tuning, now: u64, rng: &mut impl Rng) {
         self.x += self.vx * dt;
         self.y += self.vy * dt;
 
@@ -68,9 +78,9 @@ impl Player {
             self.y = 0.0;
         }
 
-        if self.up {
-            self.vx += constants::player::ACCELERATION * self.angle.cos() * dt;
-            self.vy += constants::player::ACCELERATION * self.angle.sin() * dt;
+        if self.input.thrust {
+            self.vx += tuning.acceleration * self.angle.cos() * dt;
+            self.vy += tuning.acceleration * self.angle.sin() * dt;
 
             let fps = 1000.0 / dt;
 
@@ -79,9 +89,9 @@ impl Player {
                     (constants::particle::thrust::PARTICLES_PER_SECOND / fps) as i32;
 
                 (0..num_particles).for_each(|_| {
-                    let vel = -rand::rng().random_range(constants::particle::thrust::VEL_RANGE);
+                    let vel = -rng.random_range(constants::particle::thrust::VEL_RANGE);
                     let angle = self.angle
-                        + rand::rng().random_range(
+                        + rng.random_range(
                             -constants::particle::thrust::ANGLE_OFFSET
                                 ..constants::particle::thrust::ANGLE_OFFSET,
                         );
@@ -90,19 +100,19 @@ impl Player {
                     let vy = vel * angle.sin() + self.vy;
 
                     self.particles_to_spawn
-                        .push(Particle::new(self.x, self.y, vx, vy))
+                        .push(Particle::new(self.x, self.y, vx, vy, now, rng))
                 });
 
-                self.last_thrust_particle = unsafe { SDL_GetTicks64() };
+                self.last_thrust_particle = now;
             } else {
                 let ms_per_particle =
                     (1000.0 / constants::particle::thrust::PARTICLES_PER_SECOND) as u64;
 
-                if self.last_thrust_particle + ms_per_particle < unsafe { SDL_GetTicks64() } {
-                    self.last_thrust_particle = unsafe { SDL_GetTicks64() };
-                    let vel = -rand::rng().random_range(constants::particle::thrust::VEL_RANGE);
+                if self.last_thrust_particle + ms_per_particle < now {
+                    self.last_thrust_particle = now;
+                    let vel = -rng.random_range(constants::particle::thrust::VEL_RANGE);
                     let angle = self.angle
-                        + rand::rng().random_range(
+                        + rng.random_range(
                             -constants::particle::thrust::ANGLE_OFFSET
                                 ..constants::particle::thrust::ANGLE_OFFSET,
                         );
@@ -111,24 +121,24 @@ impl Player {
                     let vy = vel * angle.sin() + self.vy;
 
                     self.particles_to_spawn
-                        .push(Particle::new(self.x, self.y, vx, vy));
+                        .push(Particle::new(self.x, self.y, vx, vy, now, rng));
                 }
             }
         }
 
-        self.vx *= constants::player::DECELERATION.powf(dt);
-        self.vy *= constants::player::DECELERATION.powf(dt);
+        self.vx *= tuning.deceleration.powf(dt);
+        self.vy *= tuning.deceleration.powf(dt);
 
-        if self.left == self.right {
+        if self.input.left == self.input.right {
             return;
         }
 
-        if self.left {
-            self.angle -= constants::player::TURN_SPEED * dt;
+        if self.input.left {
+            self.angle -= tuning.turn_speed * dt;
         }
 
-        if self.right {
-            self.angle += constants::player::TURN_SPEED * dt;
+        if self.input.right {
+            self.angle += tuning.turn_speed * dt;
         }
     }
 
@@ -164,13 +174,24 @@ impl Player {
         Ok(())
     }
 
-    pub fn handle_key_event(&mut self, key: Keycode, pressed: bool) {
-        match key {
-            Keycode::A | Keycode::Left => self.left = pressed,
-            Keycode:: D | Keycode::Right => self.right = pressed,
-            Keycode:: W | Keycode::Up => self.up = pressed,
-            _ => {}
+    pub fn set_input(&mut self, input: Input) {
+        self.input = input;
+    }
+
+    pub fn should_shoot(&mut self, tuning: &Tuning, now: u64) -> bool {
+        let autofire = tuning.autofire_rate > 0.0 && now >= self.next_autofire;
+        let shoot = self.input.fire && (!self.fire_held || autofire);
+        self.fire_held = self.input.fire;
+
+        if shoot && tuning.autofire_rate > 0.0 {
+            self.next_autofire = now + (1000.0 / tuning.autofire_rate) as u64;
         }
+
+        shoot
+    }
+
+    pub fn is_thrusting(&self) -> bool {
+        self.input.thrust
     }
 
     pub fn get_particles(&mut self) -> Vec<Particle> {
@@ -179,10 +200,10 @@ impl Player {
         particles
     }
 
-    pub fn shoot_bullet(&self) -> Bullet {
+    pub fn shoot_bullet(&self, owner: usize, now: u64) -> Bullet {
         let x = self.x - constants::player::PLAYER_SHAPE[0][1] * self.angle.cos();
         let y = self.y - constants::player::PLAYER_SHAPE[0][1] * self.angle.sin();
-        Bullet::new(x, y, self.angle, true)
+        Bullet::new(x, y, self.angle, Some(owner), now)
     }
 
     pub fn get_x(&self) -> f32 {
@@ -193,6 +214,10 @@ impl Player {
         self.y
     }
 
+    pub fn get_angle(&self) -> f32 {
+        self.angle
+    }
+
     pub fn get_hitbox(&self) -> Vec<(f32, f32)> {
         constants::player::PLAYER_SHAPE
             .iter()
@@ -231,4 +256,65 @@ impl Player {
         self.vx += force.0;
         self.vy += force.1;
     }
+
+    pub fn add_score(&mut self, points: u64) {
+        self.score = self.score.saturating_add(points);
+
+        if self.score > self.next_life_points {
+            self.next_life_points = self.next_life_points.saturating_add(constants::player::POINTS_PER_LIFE);
+            self.lives += 1;
+        }
+    }
+
+    pub fn set_score(&mut self, score: u64) {
+        self.score = score;
+        self.next_life_points = (score / constants::player::POINTS_PER_LIFE + 1)
+            .saturating_mul(constants::player::POINTS_PER_LIFE);
+    }
+
+    pub fn get_score(&self) -> u64 {
+        self.score
+    }
+
+    pub fn get_lives(&self) -> u32 {
+        self.lives
+    }
+
+    pub fn set_lives(&mut self, lives: u32) {
+        self.lives = lives;
+    }
+
+    pub fn lose_life(&mut self) {
+        self.lives -= 1;
+    }
+
+    pub fn reset_stats(&mut self) {
+        self.lives = constants::player::START_LIVES;
+        self.next_life_points = constants::player::POINTS_PER_LIFE;
+        self.score = 0;
+    }
+
+    pub fn write(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.x.to_le_bytes());
+        buf.extend_from_slice(&self.y.to_le_bytes());
+        buf.extend_from_slice(&self.vx.to_le_bytes());
+        buf.extend_from_slice(&self.vy.to_le_bytes());
+        buf.extend_from_slice(&self.angle.to_le_bytes());
+        buf.extend_from_slice(&self.lives.to_le_bytes());
+        buf.extend_from_slice(&self.score.to_le_bytes());
+        buf.push(self.input.to_byte());
+    }
+
+    pub fn read(reader: &mut Reader) -> Option<Player> {
+        let mut player = Player::new(reader.read_f32()?, reader.read_f32()?);
+
+        player.vx = reader.read_f32()?;
+        player.vy = reader.read_f32()?;
+        player.angle = reader.read_f32()?;
+        player.lives = reader.read_u32()?;
+        player.score = reader.read_u64()?;
+        player.input = Input::from_byte(reader.read_u8()?);
+
+        Some(player)
+    }
 }
@@ -0,0 +1,200 @@
+use crate::constants;
+use crate::env::{EntityObservation, Env, EnvConfig, Info, Observation, RewardShaping};
+use crate::input::Input;
+use numpy::{IntoPyArray, PyArray2, PyArrayMethods};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use rand::Rng;
+
+#[pyclass(name = "Env", unsendable)]
+pub struct PyEnv {
+    env: Env,
+    seed: u64,
+}
+
+#[pymethods]
+impl PyEnv {
+    #[new]
+    #[pyo3(signature = (frame_skip = constants::env::DEFAULT_FRAME_SKIP, raster = None, max_steps = None))]
+    fn new(frame_skip: u32, raster: Option<(u32, u32)>, max_steps: Option<u64>) -> Self {
+        PyEnv {
+            env: Env::new(EnvConfig {
+                frame_skip,
+                raster,
+                max_steps,
+                shaping: RewardShaping::default(),
+            }),
+            seed: 0,
+        }
+    }
+
+    #[pyo3(signature = (seed = None))]
+    fn reset<'py>(&mut self, py: Python<'py>, seed: Option<u64>) -> PyResult<Bound<'py, PyDict>> {
+        self.seed = seed.unwrap_or_else(|| rand::rng().random());
+        observation_dict(py, &self.env.reset(self.seed))
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn step<'py>(
+        &mut self,
+        py: Python<'py>,
+        action: u8,
+    ) -> PyResult<(Bound<'py, PyDict>, f32, bool, Bound<'py, PyDict>)> {
+        let (observation, reward, done, info) = self.env.step(Input::from_byte(action));
+
+        Ok((
+            observation_dict(py, &observation)?,
+            reward,
+            done,
+            info_dict(py, &info)?,
+        ))
+    }
+
+    fn set_reward_weights(&mut self, per_point: f32, life_lost: f32, extra_life: f32, per_step: f32) {
+        self.env.set_shaping(RewardShaping {
+            per_point,
+            life_lost,
+            extra_life,
+            per_step,
+        });
+    }
+
+    fn set_reward_hook(&mut self, hook: Py<PyAny>) {
+        self.env.set_reward_hook(Box::new(move |_, info| {
+            Python::attach(|py| {
+                let result = info_dict(py, info)
+                    .and_then(|info| hook.call1(py, (info,)))
+                    .and_then(|r| r.extract::<f32>(py));
+
+                result.unwrap_or_else(|e| {
+                    e.print(py);
+                    0.0
+                })
+            })
+        }));
+    }
+
+    fn set_frame_skip(&mut self, frame_skip: u32) {
+        self.env.set_frame_skip(frame_skip);
+    }
+
+    #[pyo3(signature = (raster = None))]
+    fn set_raster(&mut self, raster: Option<(u32, u32)>) {
+        self.env.set_raster(raster);
+    }
+
+    #[getter]
+    fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    #[getter]
+    fn action_count(&self) -> usize {
+        constants::env::ACTION_COUNT
+    }
+
+    #[getter]
+    fn field_size(&self) -> (u32, u32) {
+        let bounds = self.env.get_field_bounds();
+        (bounds.width(), bounds.height())
+    }
+}
+
+fn entity_row(e: &EntityObservation) -> [f32; 5] {
+    [e.x, e.y, e.vx, e.vy, e.radius]
+}
+
+fn rows<'py, const N: usize>(py: Python<'py>, rows: Vec<[f32; N]>) -> PyResult<Bound<'py, PyArray2<f32>>> {
+    let count = rows.len();
+
+    rows.into_iter()
+        .flatten()
+        .collect::<Vec<f32>>()
+        .into_pyarray(py)
+        .reshape([count, N])
+}
+
+fn observation_dict<'py>(py: Python<'py>, observation: &Observation) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    let p = &observation.player;
+
+    let player = entity_row(&p.entity)
+        .into_iter()
+        .chain([p.angle, p.lives as f32, p.score as f32])
+        .collect::<Vec<f32>>();
+    dict.set_item("player", player.into_pyarray(py))?;
+
+    dict.set_item(
+        "asteroids",
+        rows(py, observation.asteroids.iter().map(entity_row).collect())?,
+    )?;
+
+    dict.set_item(
+        "aliens",
+        rows(
+            py,
+            observation
+                .aliens
+                .iter()
+                .map(|(e, t)| {
+                    let [x, y, vx, vy, r] = entity_row(e);
+                    [x, y, vx, vy, r, t.to_byte() as f32]
+                })
+                .collect(),
+        )?,
+    )?;
+
+    dict.set_item(
+        "bullets",
+        rows(
+            py,
+            observation
+                .bullets
+                .iter()
+                .map(|(e, player_shot)| {
+                    let [x, y, vx, vy, r] = entity_row(e);
+                    [x, y, vx, vy, r, *player_shot as u8 as f32]
+                })
+                .collect(),
+        )?,
+    )?;
+
+    dict.set_item(
+        "black_holes",
+        rows(py, observation.black_holes.iter().map(entity_row).collect())?,
+    )?;
+
+    if let (Some(frame), Some((width, height))) = (&observation.frame, observation.frame_size) {
+        dict.set_item(
+            "frame",
+            frame
+                .clone()
+                .into_pyarray(py)
+                .reshape([height as usize, width as usize])?,
+        )?;
+    }
+
+    Ok(dict)
+}
+
+fn info_dict<'py>(py: Python<'py>, info: &Info) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+
+    dict.set_item("score", info.score)?;
+    dict.set_item("points", info.points)?;
+    dict.set_item("lives", info.lives)?;
+    dict.set_item("lives_lost", info.lives_lost)?;
+    dict.set_item("extra_lives", info.extra_lives)?;
+    dict.set_item("steps", info.steps)?;
+    dict.set_item("time", info.time)?;
+
+    Ok(dict)
+}
+
+#[pymodule]
+fn asteroids(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyEnv>()?;
+    m.add("ACTION_COUNT", constants::env::ACTION_COUNT)?;
+
+    Ok(())
+}
@@ -0,0 +1,182 @@
+use crate::alien::ShootingType;
+use crate::constants;
+use crate::input::MenuInput;
+use crate::locale::Locale;
+use crate::ui::{Menu, MenuEvent, Widget};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::{BlendMode, Canvas};
+use sdl2::video::Window;
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct Hazards {
+    pub asteroid_sizes: [bool; 3],
+    pub alien_types: [bool; 3],
+    pub black_holes: bool,
+    pub asteroid_rate: f32,
+    pub alien_rate: f32,
+    pub black_hole_rate: f32,
+}
+
+impl Default for Hazards {
+    fn default() -> Self {
+        Hazards {
+            asteroid_sizes: [true; 3],
+            alien_types: [false; 3],
+            black_holes: false,
+            asteroid_rate: constants::sandbox::ASTEROID_RATES[constants::sandbox::DEFAULT_ASTEROID_RATE],
+            alien_rate: constants::sandbox::ALIEN_RATES[constants::sandbox::DEFAULT_ALIEN_RATE],
+            black_hole_rate: constants::sandbox::BLACK_HOLE_RATES[constants::sandbox::DEFAULT_BLACK_HOLE_RATE],
+        }
+    }
+}
+
+impl Hazards {
+    pub fn get_asteroid_sizes(&self) -> Vec<usize> {
+        (0..3).filter(|&i| self.asteroid_sizes[i]).collect()
+    }
+
+    pub fn get_alien_types(&self) -> Vec<ShootingType> {
+        ShootingType::ALL
+            .into_iter()
+            .filter(|t| self.alien_types[t.to_byte() as usize])
+            .collect()
+    }
+
+    pub fn get_next_spawn(now: u64, rate: f32) -> u64 {
+        now + (constants::sandbox::MS_PER_MINUTE / rate) as u64
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct Sandbox {
+    pub hazards: Hazards,
+    pub invulnerable: bool,
+    pub infinite_lives: bool,
+}
+
+impl Default for Sandbox {
+    fn default() -> Self {
+        Sandbox {
+            hazards: Hazards::default(),
+            invulnerable: false,
+            infinite_lives: true,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum SandboxAction {
+    Start(Sandbox),
+    Close,
+}
+
+pub struct SandboxMenu {
+    open: bool,
+    menu: Menu,
+}
+
+impl Default for SandboxMenu {
+    fn default() -> Self {
+        let labels = constants::sandbox::LABELS;
+        let sandbox = Sandbox::default();
+        let toggle = |label: &str, value: bool| Widget::Toggle {
+            label: String::from(label),
+            value,
+        };
+        let rate = |label: &str, rates: &[f32], selected: usize| Widget::Choice {
+            label: String::from(label),
+            options: rates.iter().map(|r| r.to_string()).collect(),
+            selected,
+        };
+        let hazards = sandbox.hazards;
+
+        SandboxMenu {
+            open: false,
+            menu: Menu::new(
+                constants::sandbox::TITLE,
+                vec![
+                    toggle(labels[0], hazards.asteroid_sizes[0]),
+                    toggle(labels[1], hazards.asteroid_sizes[1]),
+                    toggle(labels[2], hazards.asteroid_sizes[2]),
+                    rate(labels[3], &constants::sandbox::ASTEROID_RATES, constants::sandbox::DEFAULT_ASTEROID_RATE),
+                    toggle(labels[4], hazards.alien_types[0]),
+                    toggle(labels[5], hazards.alien_types[1]),
+                    toggle(labels[6], hazards.alien_types[2]),
+                    rate(labels[7], &constants::sandbox::ALIEN_RATES, constants::sandbox::DEFAULT_ALIEN_RATE),
+                    toggle(labels[8], hazards.black_holes),
+                    rate(labels[9], &constants::sandbox::BLACK_HOLE_RATES, constants::sandbox::DEFAULT_BLACK_HOLE_RATE),
+                    toggle(labels[10], sandbox.invulnerable),
+                    toggle(labels[11], sandbox.infinite_lives),
+                    Widget::Button(String::from(labels[12])),
+                    Widget::Button(String::from(labels[13])),
+                ],
+            ),
+        }
+    }
+}
+
+impl SandboxMenu {
+    pub fn new() -> Self {
+        SandboxMenu::default()
+    }
+
+    pub fn open(&mut self) {
+        self.open = true;
+        self.menu.set_focus(0);
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn set_scale(&mut self, scale: f32) {
+        self.menu.set_scale(scale * constants::sandbox::MENU_SCALE);
+    }
+
+    pub fn get_sandbox(&self) -> Sandbox {
+        let m = &self.menu;
+
+        Sandbox {
+            hazards: Hazards {
+                asteroid_sizes: [m.get_toggle(0), m.get_toggle(1), m.get_toggle(2)],
+                alien_types: [m.get_toggle(4), m.get_toggle(5), m.get_toggle(6)],
+                black_holes: m.get_toggle(8),
+                asteroid_rate: constants::sandbox::ASTEROID_RATES[m.get_choice(3)],
+                alien_rate: constants::sandbox::ALIEN_RATES[m.get_choice(7)],
+                black_hole_rate: constants::sandbox::BLACK_HOLE_RATES[m.get_choice(9)],
+            },
+            invulnerable: m.get_toggle(10),
+            infinite_lives: m.get_toggle(11),
+        }
+    }
+
+    pub fn handle_input(&mut self, input: MenuInput) -> Option<SandboxAction> {
+        let action = match self.menu.handle_input(input)? {
+            MenuEvent::Activated(12) => SandboxAction::Start(self.get_sandbox()),
+            MenuEvent::Activated(13) | MenuEvent::Back => SandboxAction::Close,
+            MenuEvent::Activated(_) | MenuEvent::Changed(_) => return None,
+        };
+
+        self.close();
+
+        Some(action)
+    }
+
+    pub fn render(&self, canvas: &mut Canvas<Window>, screen_bounds: Rect, locale: &Locale) -> Result<(), String> {
+        if !self.open {
+            return Ok(());
+        }
+
+        canvas.set_blend_mode(BlendMode::Blend);
+        canvas.set_draw_color(Color::RGBA(0, 0, 0, constants::pause::DIM_ALPHA));
+        canvas.fill_rect(screen_bounds)?;
+        canvas.set_blend_mode(BlendMode::None);
+
+        self.menu.render(canvas, screen_bounds, locale)
+    }
+}
@@ -0,0 +1,771 @@
+use crate::alien::{Alien, ShootingType};
+use crate::asteroid::Asteroid;
+use crate::black_hole::BlackHole;
+use crate::bullet::Bullet;
+use crate::constants;
+use crate::event::{GameEvent, HitCause};
+use crate::input::Input;
+use crate::net::bytes::Reader;
+use crate::particle::Particle;
+use crate::player::Player;
+use crate::polygon;
+use crate::polygon::point_intersects_polygon;
+use crate::sandbox::Hazards;
+use crate::tuning::Tuning;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use sdl2::rect::Rect;
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+
+#[derive(Clone)]
+pub struct Session {
+    time: u64,
+    time_fraction: f64,
+    ticks: u64,
+    seed: u64,
+
+    next_asteroid_spawn: u64,
+    next_alien_spawn: u64,
+    next_black_hole_spawn: u64,
+
+    players: Vec<Player>,
+    friendly_fire: bool,
+    extra_lives: bool,
+    spawn_ramp: f32,
+    hazards: Option<Hazards>,
+    tuning: Tuning,
+
+    particles: Vec<Particle>,
+    bullets: Vec<Bullet>,
+    asteroids: Vec<Asteroid>,
+    aliens: Vec<Alien>,
+    black_holes: Vec<BlackHole>,
+
+    events: Vec<GameEvent>,
+}
+
+impl Session {
+    pub fn new(screen_bounds: Rect, players: usize, seed: u64) -> Self {
+        Session {
+            time: 0,
+            time_fraction: 0.0,
+            ticks: 0,
+            seed,
+
+            next_asteroid_spawn: Self::get_next_asteroid_spawn(0, 0, 1.0),
+            next_alien_spawn: 0,
+            next_black_hole_spawn: 0,
+
+            players: (0..players)
+                .map(|_| {
+                    Player::new(
+                        (screen_bounds.width() / 2) as f32,
+                        (screen_bounds.height() / 2) as f32,
+                    )
+                })
+                .collect(),
+            friendly_fire: false,
+            extra_lives: true,
+            spawn_ramp: 0.0,
+            hazards: None,
+            tuning: Tuning::default(),
+
+            particles: Vec::new(),
+            bullets: Vec::new(),
+            asteroids: Vec::new(),
+            aliens: Vec::new(),
+            black_holes: Vec::new(),
+
+            events: Vec::new(),
+        }
+    }
+
+    pub fn tick(&mut self, dt: f32, screen_bounds: Rect) -> Vec<usize> {
+        let elapsed = dt as f64 * 1000.0 + self.time_fraction;
+        self.time += elapsed as u64;
+        self.time_fraction = elapsed.fract();
+        self.ticks += 1;
+        let now = self.time;
+        self.events.clear();
+
+        let mut rng = StdRng::seed_from_u64(
+            self.seed
+                .wrapping_add(self.ticks.wrapping_mul(constants::session::RNG_STREAM_MULTIPLIER)),
+        );
+
+        let top_score = self.get_top_score();
+        let spawn_rate = self.tuning.spawn_rate * (1.0 + self.spawn_ramp * now as f32 / 1000.0);
+
+        if let Some(hazards) = self.hazards {
+            self.spawn_hazards(&hazards, now, screen_bounds, &mut rng);
+        }
+
+        if self.hazards.is_none() && now > self.next_asteroid_spawn {
+            self.next_asteroid_spawn =
+                Self::get_next_asteroid_spawn(now, top_score, spawn_rate);
+
+            let (px, py) = self
+                .players
+                .first()
+                .map(|p| (p.get_x(), p.get_y()))
+                .unwrap_or((0.0, 0.0));
+            let (x, y) = Asteroid::get_spawn_location(px, py, screen_bounds, &mut rng);
+
+            let radius = rng.random_range(constants::asteroid::SPAWN_RADIUS_RANGE);
+
+            self.asteroids
+                .push(Asteroid::new(x, y, radius as f32, &mut rng));
+        }
+
+        if self.hazards.is_none()
+            && now > self.next_alien_spawn
+            && self.tuning.max_aliens > self.aliens.len() as u32
+        {
+            let res = Alien::new(top_score, screen_bounds, now, &mut rng);
+
+            if let Some(alien) = res {
+                self.next_alien_spawn =
+                    Self::get_next_alien_spawn(now, top_score, spawn_rate);
+
+                self.aliens.push(alien);
+            }
+        }
+
+        if self.hazards.is_none()
+            && now > self.next_black_hole_spawn
+            && top_score >= constants::black_hole::MIN_POINTS
+            && self.tuning.max_black_holes > self.black_holes.len() as u32
+        {
+            self.next_black_hole_spawn =
+                Self::get_next_black_hole_spawn(now, top_score, spawn_rate);
+            self.black_holes
+                .push(BlackHole::new(screen_bounds, now, &mut rng));
+        }
+
+        self.players.iter_mut().enumerate().for_each(|(i, p)| {
+            p.tick(dt, screen_bounds, &self.tuning, now, &mut rng);
+            self.particles.append(&mut p.get_particles());
+
+            if p.should_shoot(&self.tuning, now) {
+                self.bullets.push(p.shoot_bullet(i, now));
+                self.events.push(GameEvent::BulletFired {
+                    owner: Some(i),
+                    pos: (p.get_x(), p.get_y()),
+                });
+            }
+        });
+
+        self.particles.retain(|p| p.is_alive(now));
+        self.particles
+            .iter_mut()
+            .for_each(|p| p.tick(dt, screen_bounds));
+
+        let mut hit: Vec<(usize, HitCause)> = Vec::new();
+
+        self.bullets.retain(|b| {
+            let target = self.players.iter().enumerate().position(|(i, p)| {
+                let hostile = match b.get_owner() {
+                    Some(owner) => self.friendly_fire && owner != i,
+                    None => true,
+                };
+
+                hostile && point_intersects_polygon(b.get_location(), p.get_hitbox().as_slice())
+            });
+
+            if let Some(i) = target {
+                hit.push((
+                    i,
+                    match b.get_owner() {
+                        Some(owner) => HitCause::PlayerBullet(owner),
+                        None => HitCause::AlienBullet,
+                    },
+                ));
+                return false;
+            }
+            b.is_alive(now) && !b.to_die
+        });
+
+        self.bullets.iter_mut().for_each(|b| {
+            b.tick(dt, screen_bounds, now, &mut rng);
+            self.particles.append(&mut b.get_particles_to_spawn())
+        });
+
+        let mut asteroids_to_add: Vec<Asteroid> = Vec::new();
+
+        self.asteroids.retain(|a| {
+            let remove = self.bullets.iter_mut().any(|b| {
+                let line = b.get_physics_trail(dt);
+                let intersects = a
+                    .get_hitboxes(screen_bounds)
+                    .iter()
+                    .any(|hitbox| polygon::polygons_intersect(hitbox, line.as_slice()))
+                    && !b.to_die;
+
+                if intersects {
+                    b.to_die = true;
+
+                    self.events.push(GameEvent::AsteroidDestroyed {
+                        radius: a.get_radius(),
+                        pos: (a.get_x(), a.get_y()),
+                        by: b.get_owner(),
+                    });
+                }
+
+                intersects
+            });
+
+            if remove
+                && let Some(mut asteroids) = a.check_split(&mut rng)
+            {
+                asteroids_to_add.append(&mut asteroids);
+            }
+
+            !remove
+        });
+
+        self.asteroids.append(&mut asteroids_to_add);
+        self.asteroids
+            .iter_mut()
+            .for_each(|a| a.tick(dt, screen_bounds));
+
+        self.players.iter().enumerate().for_each(|(i, p)| {
+            if self.asteroids.iter().any(|a| {
+                a.get_hitboxes(screen_bounds)
+                    .iter()
+                    .any(|hitbox| polygon::polygons_intersect(hitbox, p.get_hitbox().as_slice()))
+            }) {
+                hit.push((i, HitCause::Asteroid));
+            }
+        });
+
+        self.aliens.retain(|a| {
+            let remove = self.bullets.iter_mut().any(|b| {
+                let line = b.get_physics_trail(dt);
+                let intersects = a
+                    .get_hitboxes(screen_bounds)
+                    .iter()
+                    .any(|hitbox| polygon::polygons_intersect(hitbox, line.as_slice()))
+                    && !b.to_die;
+
+                if intersects {
+                    b.to_die = true;
+
+                    self.events.push(GameEvent::AlienKilled {
+                        shooting_type: a.get_type(),
+                        pos: (a.get_x(), a.get_y()),
+                        by: b.get_owner(),
+                    });
+                }
+
+                intersects
+            });
+
+            !remove
+        });
+
+        self.aliens.iter_mut().for_each(|a| {
+            let target = self
+                .players
+                .iter()
+                .min_by(|p1, p2| {
+                    let d1 = (p1.get_x() - a.get_x()).powi(2) + (p1.get_y() - a.get_y()).powi(2);
+                    let d2 = (p2.get_x() - a.get_x()).powi(2) + (p2.get_y() - a.get_y()).powi(2);
+                    d1.total_cmp(&d2)
+                })
+                .map(|p| p.get_pos_and_vel());
+
+            if let Some(target) = target {
+                a.tick(dt, screen_bounds, top_score, target, now, &mut rng);
+            }
+
+            if let Some(bullet) = a.get_bullet() {
+                self.events.push(GameEvent::BulletFired {
+                    owner: None,
+                    pos: bullet.get_location(),
+                });
+                self.bullets.push(bullet);
+            }
+        });
+
+        self.black_holes.retain(|b| b.is_alive());
+
+        self.black_holes.iter_mut().for_each(|b| {
+            b.tick(dt, now);
+
+            self.players.iter_mut().for_each(|p| {
+                let force = b.get_force(p.get_x(), p.get_y(), dt, &self.tuning);
+                p.apply_force(force);
+            });
+        });
+
+        hit.sort_by_key(|&(i, _)| i);
+        hit.dedup_by_key(|&mut (i, _)| i);
+        hit.iter().for_each(|&(player, cause)| {
+            if let Some(p) = self.players.get(player) {
+                let assisted = self
+                    .black_holes
+                    .iter()
+                    .any(|b| b.get_force(p.get_x(), p.get_y(), dt, &self.tuning) != (0.0, 0.0));
+
+                self.events.push(GameEvent::PlayerHit {
+                    player,
+                    cause,
+                    assisted,
+                    pos: (p.get_x(), p.get_y()),
+                });
+            }
+        });
+
+        let destroyed = self
+            .events
+            .iter()
+            .any(|e| matches!(e, GameEvent::AsteroidDestroyed { .. }));
+        if destroyed && self.asteroids.is_empty() {
+            self.events.push(GameEvent::WaveCleared);
+        }
+
+        let mut i = 0;
+        while i < self.events.len() {
+            self.apply_event(self.events[i], now, &mut rng);
+            i += 1;
+        }
+
+        hit.iter().map(|&(i, _)| i).collect()
+    }
+
+    fn spawn_hazards(&mut self, hazards: &Hazards, now: u64, screen_bounds: Rect, rng: &mut impl Rng) {
+        let sizes = hazards.get_asteroid_sizes();
+        if now > self.next_asteroid_spawn && !sizes.is_empty() {
+            self.next_asteroid_spawn = Hazards::get_next_spawn(now, hazards.asteroid_rate);
+
+            let (px, py) = self
+                .players
+                .first()
+                .map(|p| (p.get_x(), p.get_y()))
+                .unwrap_or((0.0, 0.0));
+            let (x, y) = Asteroid::get_spawn_location(px, py, screen_bounds, rng);
+
+            let size = sizes[rng.random_range(0..sizes.len())];
+            let radius = rng.random_range(constants::sandbox::ASTEROID_RADII[size].clone());
+
+            self.asteroids.push(Asteroid::new(x, y, radius, rng));
+        }
+
+        let types = hazards.get_alien_types();
+        if now > self.next_alien_spawn
+            && !types.is_empty()
+            && self.tuning.max_aliens > self.aliens.len() as u32
+        {
+            self.next_alien_spawn = Hazards::get_next_spawn(now, hazards.alien_rate);
+
+            let shooting_type = types[rng.random_range(0..types.len())];
+            self.aliens
+                .push(Alien::new_of_type(shooting_type, screen_bounds, now, rng));
+        }
+
+        if now > self.next_black_hole_spawn
+            && hazards.black_holes
+            && self.tuning.max_black_holes > self.black_holes.len() as u32
+        {
+            self.next_black_hole_spawn = Hazards::get_next_spawn(now, hazards.black_hole_rate);
+            self.black_holes
+                .push(BlackHole::new(screen_bounds, now, rng));
+        }
+    }
+
+    fn apply_event(&mut self, event: GameEvent, now: u64, rng: &mut impl Rng) {
+        let (points, pos, by) = match event {
+            GameEvent::AsteroidDestroyed { radius, pos, by } => {
+                ((constants::asteroid::SCORE_PER_RADIUS / radius) as u64, pos, by)
+            }
+            GameEvent::AlienKilled {
+                shooting_type,
+                pos,
+                by,
+            } => (
+                match shooting_type {
+                    ShootingType::Random => constants::alien::random::POINTS,
+                    ShootingType::Current => constants::alien::current::POINTS,
+                    ShootingType::Future => constants::alien::future::POINTS,
+                } as u64,
+                pos,
+                by,
+            ),
+            _ => return,
+        };
+
+        self.particles
+            .append(&mut Particle::generate_explosion_particles(pos.0, pos.1, now, rng));
+
+        if let Some((player, p)) = by.and_then(|o| self.players.get_mut(o).map(|p| (o, p))) {
+            let lives = p.get_lives();
+            p.add_score(points);
+
+            if p.get_lives() > lives {
+                if self.extra_lives {
+                    self.events.push(GameEvent::ExtraLife { player });
+                } else {
+                    p.set_lives(lives);
+                }
+            }
+        }
+    }
+
+    pub fn render(&self, canvas: &mut Canvas<Window>, screen_bounds: Rect) -> Result<(), String> {
+        self.players
+            .iter()
+            .try_for_each(|p| p.render(canvas, screen_bounds))?;
+
+        self.render_field(canvas, screen_bounds)
+    }
+
+    pub fn render_field(&self, canvas: &mut Canvas<Window>, screen_bounds: Rect) -> Result<(), String> {
+        self.particles.iter().try_for_each(|p| p.render(canvas))?;
+
+        self.bullets.iter().try_for_each(|b| b.render(canvas))?;
+
+        self.asteroids
+            .iter()
+            .try_for_each(|a| a.render(canvas, screen_bounds))?;
+
+        self.aliens
+            .iter()
+            .try_for_each(|a| a.render(canvas, screen_bounds))?;
+
+        self.black_holes
+            .iter()
+            .try_for_each(|b| b.render(canvas, screen_bounds))?;
+
+        Ok(())
+    }
+
+    pub fn die(&mut self, screen_bounds: Rect) {
+        self.players.iter_mut().for_each(|p| p.die(screen_bounds));
+
+        self.asteroids.clear();
+        self.particles.clear();
+        self.bullets.clear();
+        self.aliens.clear();
+        self.black_holes.clear();
+    }
+
+    pub fn respawn(&mut self, player: usize, screen_bounds: Rect) {
+        let p = &mut self.players[player];
+        p.die(screen_bounds);
+
+        if p.get_lives() == 1 {
+            p.reset_stats();
+        } else {
+            p.lose_life();
+        }
+    }
+
+    pub fn add_player(&mut self, screen_bounds: Rect) -> usize {
+        self.players.push(Player::new(
+            (screen_bounds.width() / 2) as f32,
+            (screen_bounds.height() / 2) as f32,
+        ));
+
+        self.players.len() - 1
+    }
+
+    pub fn remove_player(&mut self, player: usize) {
+        self.players.remove(player);
+
+        self.bullets.iter_mut().for_each(|b| match b.get_owner() {
+            Some(o) if o == player => b.set_owner(None),
+            Some(o) if o > player => b.set_owner(Some(o - 1)),
+            _ => {}
+        });
+    }
+
+    pub fn set_input(&mut self, player: usize, input: Input) {
+        if let Some(p) = self.players.get_mut(player) {
+            p.set_input(input);
+        }
+    }
+
+    pub fn set_friendly_fire(&mut self, friendly_fire: bool) {
+        self.friendly_fire = friendly_fire;
+    }
+
+    pub fn set_extra_lives(&mut self, extra_lives: bool) {
+        self.extra_lives = extra_lives;
+    }
+
+    pub fn set_spawn_ramp(&mut self, spawn_ramp: f32) {
+        self.spawn_ramp = spawn_ramp;
+    }
+
+    pub fn set_hazards(&mut self, hazards: Option<Hazards>) {
+        self.hazards = hazards;
+    }
+
+    pub fn get_tuning(&self) -> &Tuning {
+        &self.tuning
+    }
+
+    pub fn get_tuning_mut(&mut self) -> &mut Tuning {
+        &mut self.tuning
+    }
+
+    pub fn add_asteroid(&mut self, asteroid: Asteroid) {
+        self.asteroids.push(asteroid);
+    }
+
+    pub fn add_alien(&mut self, alien: Alien) {
+        self.aliens.push(alien);
+    }
+
+    pub fn add_black_hole(&mut self, black_hole: BlackHole) {
+        self.black_holes.push(black_hole);
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn get_time(&self) -> u64 {
+        self.time
+    }
+
+    pub fn get_player(&self, player: usize) -> Option<&Player> {
+        self.players.get(player)
+    }
+
+    pub fn get_player_mut(&mut self, player: usize) -> Option<&mut Player> {
+        self.players.get_mut(player)
+    }
+
+    pub fn get_players(&self) -> &[Player] {
+        &self.players
+    }
+
+    pub fn get_asteroids(&self) -> &[Asteroid] {
+        &self.asteroids
+    }
+
+    pub fn get_aliens(&self) -> &[Alien] {
+        &self.aliens
+    }
+
+    pub fn get_particles(&self) -> &[Particle] {
+        &self.particles
+    }
+
+    pub fn get_bullets(&self) -> &[Bullet] {
+        &self.bullets
+    }
+
+    pub fn get_black_holes(&self) -> &[BlackHole] {
+        &self.black_holes
+    }
+
+    pub fn get_events(&self) -> &[GameEvent] {
+        &self.events
+    }
+
+    fn get_top_score(&self) -> u64 {
+        self.players.iter().map(|p| p.get_score()).max().unwrap_or(0)
+    }
+
+    pub fn write(&self, buf: &mut Vec<u8>) {
+        let players = self.players.len().min(u8::MAX as usize);
+        buf.push(players as u8);
+        self.players.iter().take(players).for_each(|p| p.write(buf));
+
+        let asteroids = self.asteroids.len().min(u16::MAX as usize);
+        buf.extend_from_slice(&(asteroids as u16).to_le_bytes());
+        self.asteroids.iter().take(asteroids).for_each(|a| a.write(buf));
+
+        let aliens = self.aliens.len().min(u8::MAX as usize);
+        buf.push(aliens as u8);
+        self.aliens.iter().take(aliens).for_each(|a| a.write(buf));
+
+        let bullets = self.bullets.len().min(u16::MAX as usize);
+        buf.extend_from_slice(&(bullets as u16).to_le_bytes());
+        self.bullets.iter().take(bullets).for_each(|b| b.write(buf));
+
+        let black_holes = self.black_holes.len().min(u8::MAX as usize);
+        buf.push(black_holes as u8);
+        self.black_holes.iter().take(black_holes).for_each(|b| b.write(buf));
+    }
+
+    pub fn hash(&self) -> u64 {
+        let mut buf = Vec::new();
+        self.write(&mut buf);
+
+        buf.extend_from_slice(&self.time.to_le_bytes());
+        buf.extend_from_slice(&self.ticks.to_le_bytes());
+        buf.extend_from_slice(&self.next_asteroid_spawn.to_le_bytes());
+        buf.extend_from_slice(&self.next_alien_spawn.to_le_bytes());
+        buf.extend_from_slice(&self.next_black_hole_spawn.to_le_bytes());
+
+        buf.iter().fold(constants::session::HASH_OFFSET, |hash, &b| {
+            (hash ^ b as u64).wrapping_mul(constants::session::HASH_PRIME)
+        })
+    }
+
+    pub fn read(reader: &mut Reader) -> Option<Session> {
+        let players = (0..reader.read_u8()?)
+            .map(|_| Player::read(reader))
+            .collect::<Option<Vec<Player>>>()?;
+
+        let asteroids = (0..reader.read_u16()?)
+            .map(|_| Asteroid::read(reader))
+            .collect::<Option<Vec<Asteroid>>>()?;
+
+        let aliens = (0..reader.read_u8()?)
+            .map(|_| Alien::read(reader))
+            .collect::<Option<Vec<Alien>>>()?;
+
+        let bullets = (0..reader.read_u16()?)
+            .map(|_| Bullet::read(reader))
+            .collect::<Option<Vec<Bullet>>>()?;
+
+        let black_holes = (0..reader.read_u8()?)
+            .map(|_| BlackHole::read(reader))
+            .collect::<Option<Vec<BlackHole>>>()?;
+
+        Some(Session {
+            time: 0,
+            time_fraction: 0.0,
+            ticks: 0,
+            seed: 0,
+
+            next_asteroid_spawn: 0,
+            next_alien_spawn: 0,
+            next_black_hole_spawn: 0,
+
+            players,
+            friendly_fire: false,
+            extra_lives: true,
+            spawn_ramp: 0.0,
+            hazards: None,
+            tuning: Tuning::default(),
+
+            particles: Vec::new(),
+            bullets,
+            asteroids,
+            aliens,
+            black_holes,
+
+            events: Vec::new(),
+        })
+    }
+
+    fn get_next_asteroid_spawn(now: u64, score: u64, rate: f32) -> u64 {
+        let delay = constants::asteroid::SPAWN_DELAYS
+            .iter()
+            .map(|d| if score >= d[0] { d[1] } else { u64::MAX })
+            .min()
+            .unwrap();
+
+        if delay == u64::MAX {
+            return 0;
+        }
+
+        now + (delay as f32 / rate) as u64
+    }
+
+    fn get_next_alien_spawn(now: u64, score: u64, rate: f32) -> u64 {
+        let delay = constants::alien::SPAWN_DELAYS
+            .iter()
+            .map(|d| if score >= d[0] { d[1] } else { u64::MAX })
+            .min()
+            .unwrap();
+
+        if delay == u64::MAX {
+            return 0;
+        }
+
+        now + (delay as f32 / rate) as u64
+    }
+
+    fn get_next_black_hole_spawn(now: u64, score: u64, rate: f32) -> u64 {
+        let delay = constants::black_hole::SPAWN_DELAYS
+            .iter()
+            .map(|d| if score >= d[0] { d[1] } else { u64::MAX })
+            .min()
+            .unwrap();
+
+        if delay == u64::MAX {
+            return 0;
+        }
+
+        now + (delay as f32 / rate) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::net;
+
+    fn run(seed: u64, frames: u32, input: Input) -> Session {
+        let bounds = net::field_bounds();
+        let mut session = Session::new(bounds, 2, seed);
+
+        (0..frames).for_each(|_| {
+            session.set_input(0, input);
+            session.tick(constants::net::TICK_MS as f32 / 1000.0, bounds);
+        });
+
+        session
+    }
+
+    #[test]
+    fn write_read_round_trip() {
+        let input = Input {
+            left: true,
+            right: false,
+            thrust: true,
+            fire: true,
+        };
+        let session = run(42, 600, input);
+
+        let mut buf = Vec::new();
+        session.write(&mut buf);
+
+        let read = Session::read(&mut Reader::new(&buf)).unwrap();
+        let mut reread = Vec::new();
+        read.write(&mut reread);
+
+        assert_eq!(buf, reread);
+        assert_eq!(read.get_players().len(), 2);
+        assert_eq!(read.get_asteroids().len(), session.get_asteroids().len());
+        assert!(Session::read(&mut Reader::new(&buf[..buf.len() - 1])).is_none());
+    }
+
+    #[test]
+    fn clock_keeps_fractional_milliseconds() {
+        let bounds = net::field_bounds();
+
+        let mut session = Session::new(bounds, 0, 1);
+        (0..1000).for_each(|_| {
+            session.tick(1.0 / 60.0, bounds);
+        });
+        assert!(session.get_time().abs_diff(16_667) <= 1);
+
+        let mut slow = Session::new(bounds, 0, 1);
+        (0..1000).for_each(|_| {
+            slow.tick(0.1 / 240.0, bounds);
+        });
+        assert!(slow.get_time().abs_diff(417) <= 1);
+    }
+
+    #[test]
+    fn hash_is_deterministic() {
+        let input = Input {
+            left: false,
+            right: true,
+            thrust: true,
+            fire: true,
+        };
+
+        assert_eq!(run(7, 600, input).hash(), run(7, 600, input).hash());
+        assert_ne!(run(7, 600, input).hash(), run(7, 600, Input::default()).hash());
+        assert_ne!(run(7, 600, input).hash(), run(8, 600, input).hash());
+    }
+}
@@ -0,0 +1,241 @@
+use crate::constants;
+use crate::input::KeyBindings;
+use crate::tuning::Tuning;
+use sdl2::keyboard::Keycode;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn get_name(self) -> &'static str {
+        constants::settings::DIFFICULTY_NAMES[self as usize]
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Difficulty::ALL.into_iter().find(|d| d.get_name() == name)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct Settings {
+    pub fullscreen: bool,
+    pub vsync: bool,
+    pub resolution: (u32, u32),
+
+    pub master_volume: f32,
+    pub sfx_volume: f32,
+
+    pub bindings: KeyBindings,
+
+    pub difficulty: Difficulty,
+    pub autofire: bool,
+    pub start_lives: u32,
+    pub attract_ship: bool,
+
+    pub large_text: bool,
+    pub game_speed: f32,
+
+    pub locale: &'static str,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            fullscreen: true,
+            vsync: true,
+            resolution: constants::settings::RESOLUTIONS[0],
+
+            master_volume: 1.0,
+            sfx_volume: 1.0,
+
+            bindings: KeyBindings::default(),
+
+            difficulty: Difficulty::Normal,
+            autofire: false,
+            start_lives: constants::player::START_LIVES,
+            attract_ship: true,
+
+            large_text: false,
+            game_speed: 1.0,
+
+            locale: constants::locale::DEFAULT,
+        }
+    }
+}
+
+impl Settings {
+    pub fn load() -> Result<Self, String> {
+        let mut settings = Settings::default();
+
+        let Some(path) = get_config_path(constants::settings::FILE) else {
+            return Ok(settings);
+        };
+
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(settings),
+            Err(e) => return Err(e.to_string()),
+        };
+
+        contents
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .for_each(|line| {
+                let result = line
+                    .split_once('=')
+                    .ok_or(format!("{}{}", constants::strings::INVALID_VALUE_ERROR, line))
+                    .and_then(|(key, value)| settings.set(key.trim(), value.trim()));
+
+                if let Err(e) = result {
+                    println!("{}{}", constants::strings::SETTINGS_ERROR, e);
+                }
+            });
+
+        Ok(settings)
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = get_config_path(constants::settings::FILE)
+            .ok_or(String::from(constants::strings::CONFIG_DIR_ERROR))?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+
+        let contents = constants::settings::KEYS
+            .iter()
+            .filter_map(|key| self.get(key).map(|value| format!("{}={}\n", key, value)))
+            .collect::<String>();
+
+        fs::write(path, contents).map_err(|e| e.to_string())
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let invalid = || format!("{}{}", constants::strings::INVALID_VALUE_ERROR, value);
+
+        let flag = || value.parse::<bool>().map_err(|_| invalid());
+        let volume = || {
+            value
+                .parse::<f32>()
+                .ok()
+                .filter(|v| v.is_finite())
+                .map(|v| v.clamp(0.0, 1.0))
+                .ok_or_else(invalid)
+        };
+        let key_code = || Keycode::from_name(value).ok_or_else(invalid);
+
+        match key {
+            "fullscreen" => self.fullscreen = flag()?,
+            "vsync" => self.vsync = flag()?,
+            "resolution" => {
+                self.resolution = value
+                    .split_once('x')
+                    .and_then(|(w, h)| Some((w.parse::<u32>().ok()?, h.parse::<u32>().ok()?)))
+                    .filter(|&(w, h)| w > 0 && h > 0)
+                    .ok_or_else(invalid)?
+            }
+            "master_volume" => self.master_volume = volume()?,
+            "sfx_volume" => self.sfx_volume = volume()?,
+            "key_left" => self.bindings.left = key_code()?,
+            "key_right" => self.bindings.right = key_code()?,
+            "key_thrust" => self.bindings.thrust = key_code()?,
+            "key_fire" => self.bindings.fire = key_code()?,
+            "difficulty" => self.difficulty = Difficulty::from_name(value).ok_or_else(invalid)?,
+            "autofire" => self.autofire = flag()?,
+            "start_lives" => {
+                self.start_lives = value
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|l| constants::settings::START_LIVES.contains(l))
+                    .ok_or_else(invalid)?
+            }
+            "attract_ship" => self.attract_ship = flag()?,
+            "large_text" => self.large_text = flag()?,
+            "game_speed" => {
+                self.game_speed = value
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|s| constants::settings::GAME_SPEEDS.contains(s))
+                    .ok_or_else(invalid)?
+            }
+            "locale" => {
+                self.locale = constants::locale::LOCALES
+                    .iter()
+                    .map(|&(code, _, _)| code)
+                    .find(|&code| code == value)
+                    .ok_or_else(invalid)?
+            }
+            _ => return Err(format!("{}{}", constants::strings::UNKNOWN_KEY_ERROR, key)),
+        }
+
+        Ok(())
+    }
+
+    pub fn get(&self, key: &str) -> Option<String> {
+        Some(match key {
+            "fullscreen" => self.fullscreen.to_string(),
+            "vsync" => self.vsync.to_string(),
+            "resolution" => format!("{}x{}", self.resolution.0, self.resolution.1),
+            "master_volume" => self.master_volume.to_string(),
+            "sfx_volume" => self.sfx_volume.to_string(),
+            "key_left" => self.bindings.left.name(),
+            "key_right" => self.bindings.right.name(),
+            "key_thrust" => self.bindings.thrust.name(),
+            "key_fire" => self.bindings.fire.name(),
+            "difficulty" => self.difficulty.get_name().to_string(),
+            "autofire" => self.autofire.to_string(),
+            "start_lives" => self.start_lives.to_string(),
+            "attract_ship" => self.attract_ship.to_string(),
+            "large_text" => self.large_text.to_string(),
+            "game_speed" => self.game_speed.to_string(),
+            "locale" => self.locale.to_string(),
+            _ => return None,
+        })
+    }
+
+    pub fn apply_tuning(&self, tuning: &mut Tuning) {
+        let difficulty = self.difficulty as usize;
+
+        tuning.spawn_rate = constants::settings::DIFFICULTY_SPAWN_RATES[difficulty];
+        tuning.max_aliens = constants::settings::DIFFICULTY_MAX_ALIENS[difficulty];
+        tuning.autofire_rate = if self.autofire {
+            constants::settings::AUTOFIRE_RATE
+        } else {
+            0.0
+        };
+    }
+
+    pub fn has_standard_rules(&self) -> bool {
+        let defaults = Settings::default();
+
+        self.difficulty == defaults.difficulty
+            && self.autofire == defaults.autofire
+            && self.start_lives == defaults.start_lives
+    }
+
+    pub fn get_text_scale(&self) -> f32 {
+        if self.large_text {
+            constants::settings::LARGE_TEXT_SCALE
+        } else {
+            1.0
+        }
+    }
+}
+
+pub fn get_config_path(file: &str) -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join(constants::settings::DIRECTORY).join(file))
+}
@@ -0,0 +1,317 @@
+use crate::constants;
+use crate::input::MenuInput;
+use crate::locale::Locale;
+use crate::settings::{Difficulty, Settings};
+use crate::ui::{Menu, MenuEvent, Widget};
+use sdl2::keyboard::Keycode;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::{BlendMode, Canvas};
+use sdl2::video::Window;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum SettingsEvent {
+    Changed,
+    Closed,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Page {
+    Categories,
+    Video,
+    Audio,
+    Controls,
+    Gameplay,
+    Accessibility,
+    Language,
+}
+
+const PAGES: [Page; 6] = [
+    Page::Video,
+    Page::Audio,
+    Page::Controls,
+    Page::Gameplay,
+    Page::Accessibility,
+    Page::Language,
+];
+
+pub struct SettingsMenu {
+    open: bool,
+    page: Page,
+    menu: Menu,
+    settings: Settings,
+    binding: Option<usize>,
+}
+
+impl Default for SettingsMenu {
+    fn default() -> Self {
+        SettingsMenu {
+            open: false,
+            page: Page::Categories,
+            menu: Menu::new(constants::strings::SETTINGS_TEXT, Vec::new()),
+            settings: Settings::default(),
+            binding: None,
+        }
+    }
+}
+
+impl SettingsMenu {
+    pub fn new() -> Self {
+        SettingsMenu::default()
+    }
+
+    pub fn open(&mut self, settings: &Settings) {
+        self.open = true;
+        self.settings = *settings;
+        self.binding = None;
+        self.show(Page::Categories, 0);
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn get_settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    pub fn handle_key_event(&mut self, key: Keycode) -> Option<SettingsEvent> {
+        if let Some(index) = self.binding.take() {
+            if key != Keycode::ESCAPE {
+                let bindings = &mut self.settings.bindings;
+                match index {
+                    0 => bindings.left = key,
+                    1 => bindings.right = key,
+                    2 => bindings.thrust = key,
+                    _ => bindings.fire = key,
+                }
+            }
+
+            self.show(Page::Controls, index);
+            return (key != Keycode::ESCAPE).then_some(SettingsEvent::Changed);
+        }
+
+        MenuInput::from_key(key).and_then(|input| self.handle_input(input))
+    }
+
+    pub fn handle_input(&mut self, input: MenuInput) -> Option<SettingsEvent> {
+        if self.binding.is_some() {
+            return None;
+        }
+
+        let event = self.menu.handle_input(input)?;
+
+        if self.page == Page::Categories {
+            return match event {
+                MenuEvent::Activated(i) if i < PAGES.len() => {
+                    self.show(PAGES[i], 0);
+                    None
+                }
+                MenuEvent::Activated(i) if i == PAGES.len() => {
+                    self.settings = Settings::default();
+                    self.show(Page::Categories, i);
+                    Some(SettingsEvent::Changed)
+                }
+                _ => {
+                    self.close();
+                    Some(SettingsEvent::Closed)
+                }
+            };
+        }
+
+        let back = self.menu.get_item_count() - 1;
+
+        match event {
+            MenuEvent::Changed(i) => {
+                self.apply(i);
+                Some(SettingsEvent::Changed)
+            }
+            MenuEvent::Activated(i) if self.page == Page::Controls && i < back => {
+                self.binding = Some(i);
+                self.show(Page::Controls, i);
+                None
+            }
+            MenuEvent::Activated(i) if i < back => None,
+            _ => {
+                let index = PAGES.iter().position(|&p| p == self.page).unwrap_or(0);
+                self.show(Page::Categories, index);
+                None
+            }
+        }
+    }
+
+    pub fn render(&self, canvas: &mut Canvas<Window>, screen_bounds: Rect, locale: &Locale) -> Result<(), String> {
+        if !self.open {
+            return Ok(());
+        }
+
+        canvas.set_blend_mode(BlendMode::Blend);
+        canvas.set_draw_color(Color::RGBA(0, 0, 0, constants::pause::DIM_ALPHA));
+        canvas.fill_rect(screen_bounds)?;
+        canvas.set_blend_mode(BlendMode::None);
+
+        self.menu.render(canvas, screen_bounds, locale)
+    }
+
+    fn show(&mut self, page: Page, focus: usize) {
+        let s = &self.settings;
+        let locale = Locale::load(s.locale).unwrap_or_default();
+        let labels = constants::settings::LABELS;
+        let toggle = |label: &str, value: bool| Widget::Toggle {
+            label: String::from(label),
+            value,
+        };
+        let slider = |label: &str, value: f32| Widget::Slider {
+            label: String::from(label),
+            value,
+            min: 0.0,
+            max: 1.0,
+            step: constants::settings::VOLUME_STEP,
+        };
+        let choice = |label: &str, options: Vec<String>, selected: Option<usize>| Widget::Choice {
+            label: String::from(label),
+            options,
+            selected: selected.unwrap_or(0),
+        };
+        let binding = |i: usize, label: &str, key: Keycode| {
+            Widget::Button(if self.binding == Some(i) {
+                format!(
+                    "{} {}",
+                    locale.translate(label),
+                    locale.translate(constants::strings::PRESS_KEY_TEXT)
+                )
+            } else {
+                format!("{} {}", locale.translate(label), key.name())
+            })
+        };
+        let back = Widget::Button(String::from(constants::pause::BACK_LABEL));
+
+        let (title, items) = match page {
+            Page::Categories => (
+                constants::strings::SETTINGS_TEXT,
+                constants::settings::CATEGORIES
+                    .iter()
+                    .map(|s| Widget::Button(s.to_string()))
+                    .collect(),
+            ),
+            Page::Video => (
+                constants::settings::CATEGORIES[0],
+                vec![
+                    toggle(labels[0], s.fullscreen),
+                    toggle(labels[1], s.vsync),
+                    choice(
+                        labels[2],
+                        constants::settings::RESOLUTIONS
+                            .iter()
+                            .map(|(w, h)| format!("{}x{}", w, h))
+                            .collect(),
+                        constants::settings::RESOLUTIONS
+                            .iter()
+                            .position(|&r| r == s.resolution),
+                    ),
+                    back,
+                ],
+            ),
+            Page::Audio => (
+                constants::settings::CATEGORIES[1],
+                vec![slider(labels[3], s.master_volume), slider(labels[4], s.sfx_volume), back],
+            ),
+            Page::Controls => (
+                constants::settings::CATEGORIES[2],
+                vec![
+                    binding(0, labels[5], s.bindings.left),
+                    binding(1, labels[6], s.bindings.right),
+                    binding(2, labels[7], s.bindings.thrust),
+                    binding(3, labels[8], s.bindings.fire),
+                    back,
+                ],
+            ),
+            Page::Gameplay => (
+                constants::settings::CATEGORIES[3],
+                vec![
+                    choice(
+                        labels[9],
+                        Difficulty::ALL.iter().map(|d| d.get_name().to_string()).collect(),
+                        Some(s.difficulty as usize),
+                    ),
+                    toggle(labels[10], s.autofire),
+                    choice(
+                        labels[11],
+                        constants::settings::START_LIVES.map(|l| l.to_string()).collect(),
+                        constants::settings::START_LIVES.into_iter().position(|l| l == s.start_lives),
+                    ),
+                    toggle(labels[15], s.attract_ship),
+                    back,
+                ],
+            ),
+            Page::Accessibility => (
+                constants::settings::CATEGORIES[4],
+                vec![
+                    toggle(labels[12], s.large_text),
+                    choice(
+                        labels[13],
+                        constants::settings::GAME_SPEEDS
+                            .iter()
+                            .map(|&g| format!("{}%", (g * 100.0) as u32))
+                            .collect(),
+                        constants::settings::GAME_SPEEDS
+                            .iter()
+                            .position(|&g| g == s.game_speed),
+                    ),
+                    back,
+                ],
+            ),
+            Page::Language => (
+                constants::settings::CATEGORIES[5],
+                vec![
+                    choice(
+                        labels[14],
+                        constants::locale::LOCALES
+                            .iter()
+                            .map(|(_, name, _)| name.to_string())
+                            .collect(),
+                        constants::locale::LOCALES
+                            .iter()
+                            .position(|&(code, _, _)| code == s.locale),
+                    ),
+                    back,
+                ],
+            ),
+        };
+
+        self.page = page;
+        self.menu = Menu::new(title, items);
+        self.menu.set_scale(self.settings.get_text_scale());
+        self.menu.set_focus(focus);
+    }
+
+    fn apply(&mut self, index: usize) {
+        let menu = &self.menu;
+        let s = &mut self.settings;
+
+        match (self.page, index) {
+            (Page::Video, 0) => s.fullscreen = menu.get_toggle(0),
+            (Page::Video, 1) => s.vsync = menu.get_toggle(1),
+            (Page::Video, 2) => s.resolution = constants::settings::RESOLUTIONS[menu.get_choice(2)],
+            (Page::Audio, 0) => s.master_volume = menu.get_slider(0),
+            (Page::Audio, 1) => s.sfx_volume = menu.get_slider(1),
+            (Page::Gameplay, 0) => s.difficulty = Difficulty::ALL[menu.get_choice(0)],
+            (Page::Gameplay, 1) => s.autofire = menu.get_toggle(1),
+            (Page::Gameplay, 2) => {
+                s.start_lives = *constants::settings::START_LIVES.start() + menu.get_choice(2) as u32
+            }
+            (Page::Gameplay, 3) => s.attract_ship = menu.get_toggle(3),
+            (Page::Accessibility, 0) => {
+                s.large_text = menu.get_toggle(0);
+                self.show(Page::Accessibility, 0);
+            }
+            (Page::Accessibility, 1) => s.game_speed = constants::settings::GAME_SPEEDS[menu.get_choice(1)],
+            (Page::Language, 0) => s.locale = constants::locale::LOCALES[menu.get_choice(0)].0,
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use crate::audio::ExplosionSize;
+use crate::constants;
+use crate::event::{GameEvent, HitCause, Subscriber};
+use crate::session::Session;
+use crate::settings;
+use std::fs;
+use std::io::ErrorKind;
+
+#[derive(Copy, Clone, Default, PartialEq)]
+pub struct Stats {
+    pub games_played: u64,
+    pub shots_fired: u64,
+    pub shots_hit: u64,
+    pub asteroids_destroyed: [u64; 3],
+    pub aliens_killed: [u64; 3],
+    pub deaths: [u64; 3],
+    pub time_survived: f32,
+    pub distance_flown: f32,
+    pub max_combo: u64,
+
+    combo: u64,
+    last_kill: f32,
+}
+
+impl Stats {
+    pub fn load() -> Result<Self, String> {
+        let mut stats = Stats::default();
+
+        let Some(path) = settings::get_config_path(constants::stats::FILE) else {
+            return Ok(stats);
+        };
+
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(stats),
+            Err(e) => return Err(e.to_string()),
+        };
+
+        contents
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .for_each(|line| {
+                let result = line
+                    .split_once('=')
+                    .ok_or(format!("{}{}", constants::strings::INVALID_VALUE_ERROR, line))
+                    .and_then(|(key, value)| stats.set(key.trim(), value.trim()));
+
+                if let Err(e) = result {
+                    println!("{}{}", constants::strings::STATS_ERROR, e);
+                }
+            });
+
+        Ok(stats)
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = settings::get_config_path(constants::stats::FILE)
+            .ok_or(String::from(constants::strings::CONFIG_DIR_ERROR))?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+
+        let contents = constants::stats::KEYS
+            .iter()
+            .filter_map(|key| self.get(key).map(|value| format!("{}={}\n", key, value)))
+            .collect::<String>();
+
+        fs::write(path, contents).map_err(|e| e.to_string())
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let invalid = || format!("{}{}", constants::strings::INVALID_VALUE_ERROR, value);
+        let count = || value.parse::<u64>().map_err(|_| invalid());
+        let amount = || {
+            value
+                .parse::<f32>()
+                .ok()
+                .filter(|v| v.is_finite() && *v >= 0.0)
+                .ok_or_else(invalid)
+        };
+
+        match key {
+            "games_played" => self.games_played = count()?,
+            "shots_fired" => self.shots_fired = count()?,
+            "shots_hit" => self.shots_hit = count()?,
+            "asteroids_small" => self.asteroids_destroyed[0] = count()?,
+            "asteroids_medium" => self.asteroids_destroyed[1] = count()?,
+            "asteroids_large" => self.asteroids_destroyed[2] = count()?,
+            "aliens_random" => self.aliens_killed[0] = count()?,
+            "aliens_current" => self.aliens_killed[1] = count()?,
+            "aliens_future" => self.aliens_killed[2] = count()?,
+            "deaths_asteroid" => self.deaths[0] = count()?,
+            "deaths_alien_bullet" => self.deaths[1] = count()?,
+            "deaths_black_hole" => self.deaths[2] = count()?,
+            "time_survived" => self.time_survived = amount()?,
+            "distance_flown" => self.distance_flown = amount()?,
+            "max_combo" => self.max_combo = count()?,
+            _ => return Err(format!("{}{}", constants::strings::UNKNOWN_KEY_ERROR, key)),
+        }
+
+        Ok(())
+    }
+
+    pub fn get(&self, key: &str) -> Option<String> {
+        Some(match key {
+            "games_played" => self.games_played.to_string(),
+            "shots_fired" => self.shots_fired.to_string(),
+            "shots_hit" => self.shots_hit.to_string(),
+            "asteroids_small" => self.asteroids_destroyed[0].to_string(),
+            "asteroids_medium" => self.asteroids_destroyed[1].to_string(),
+            "asteroids_large" => self.asteroids_destroyed[2].to_string(),
+            "aliens_random" => self.aliens_killed[0].to_string(),
+            "aliens_current" => self.aliens_killed[1].to_string(),
+            "aliens_future" => self.aliens_killed[2].to_string(),
+            "deaths_asteroid" => self.deaths[0].to_string(),
+            "deaths_alien_bullet" => self.deaths[1].to_string(),
+            "deaths_black_hole" => self.deaths[2].to_string(),
+            "time_survived" => self.time_survived.to_string(),
+            "distance_flown" => self.distance_flown.to_string(),
+            "max_combo" => self.max_combo.to_string(),
+            _ => return None,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.time_survived == 0.0
+    }
+
+    pub fn add(&mut self, other: &Stats) {
+        self.games_played += 1;
+        self.shots_fired += other.shots_fired;
+        self.shots_hit += other.shots_hit;
+        (0..3).for_each(|i| {
+            self.asteroids_destroyed[i] += other.asteroids_destroyed[i];
+            self.aliens_killed[i] += other.aliens_killed[i];
+            self.deaths[i] += other.deaths[i];
+        });
+        self.time_survived += other.time_survived;
+        self.distance_flown += other.distance_flown;
+        self.max_combo = self.max_combo.max(other.max_combo);
+    }
+
+    pub fn get_accuracy(&self) -> f32 {
+        if self.shots_fired == 0 {
+            return 0.0;
+        }
+
+        (self.shots_hit as f32 / self.shots_fired as f32).min(1.0)
+    }
+
+    pub fn get_lines(&self, lifetime: bool) -> Vec<String> {
+        let seconds = self.time_survived as u64;
+
+        let values = [
+            self.games_played.to_string(),
+            self.shots_fired.to_string(),
+            format!("{}%", (self.get_accuracy() * 100.0).round() as u32),
+            self.asteroids_destroyed[0].to_string(),
+            self.asteroids_destroyed[1].to_string(),
+            self.asteroids_destroyed[2].to_string(),
+            self.aliens_killed[0].to_string(),
+            self.aliens_killed[1].to_string(),
+            self.aliens_killed[2].to_string(),
+            self.deaths[0].to_string(),
+            self.deaths[1].to_string(),
+            self.deaths[2].to_string(),
+            format!("{}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60),
+            (self.distance_flown / constants::stats::DISTANCE_UNIT).round().to_string(),
+            self.max_combo.to_string(),
+        ];
+
+        values.into_iter().skip(!lifetime as usize).collect()
+    }
+
+    pub fn track(&mut self, session: &Session, dt: f32) {
+        self.time_survived += dt;
+
+        if let Some(player) = session.get_player(0) {
+            let (_, _, vx, vy) = player.get_pos_and_vel();
+            self.distance_flown += vx.hypot(vy) * dt;
+        }
+    }
+}
+
+impl Subscriber for Stats {
+    fn handle_event(&mut self, event: &GameEvent) {
+        let killed = match *event {
+            GameEvent::BulletFired { owner: Some(_), .. } => {
+                self.shots_fired += 1;
+                false
+            }
+            GameEvent::AsteroidDestroyed { radius, by: Some(_), .. } => {
+                self.asteroids_destroyed[ExplosionSize::from_radius(radius) as usize] += 1;
+                true
+            }
+            GameEvent::AlienKilled {
+                shooting_type,
+                by: Some(_),
+                ..
+            } => {
+                self.aliens_killed[shooting_type.to_byte() as usize] += 1;
+                true
+            }
+            GameEvent::PlayerHit { cause, assisted, .. } => {
+                let index = match cause {
+                    _ if assisted => 2,
+                    HitCause::Asteroid => 0,
+                    HitCause::AlienBullet | HitCause::PlayerBullet(_) => 1,
+                };
+                self.deaths[index] += 1;
+                self.combo = 0;
+                false
+            }
+            _ => false,
+        };
+
+        if killed {
+            self.shots_hit += 1;
+
+            if self.time_survived - self.last_kill > constants::stats::COMBO_WINDOW {
+                self.combo = 0;
+            }
+
+            self.combo += 1;
+            self.last_kill = self.time_survived;
+            self.max_combo = self.max_combo.max(self.combo);
+        }
+    }
+}
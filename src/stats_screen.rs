@@ -0,0 +1,134 @@
+use crate::constants;
+use crate::input::MenuInput;
+use crate::locale::Locale;
+use crate::stats::Stats;
+use crate::ui::{self, Align, Menu, MenuEvent, Widget};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::{BlendMode, Canvas};
+use sdl2::video::Window;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum StatsAction {
+    PlayAgain,
+    Close,
+}
+
+pub struct StatsScreen {
+    open: bool,
+    title: &'static str,
+    columns: Vec<Stats>,
+    lifetime: bool,
+    menu: Menu,
+    scale: f32,
+}
+
+impl Default for StatsScreen {
+    fn default() -> Self {
+        StatsScreen {
+            open: false,
+            title: constants::strings::STATS_TEXT,
+            columns: Vec::new(),
+            lifetime: true,
+            menu: Menu::new("", Vec::new()),
+            scale: 1.0,
+        }
+    }
+}
+
+impl StatsScreen {
+    pub fn new() -> Self {
+        StatsScreen::default()
+    }
+
+    pub fn open_game_over(&mut self, stats: &[Stats]) {
+        self.show(constants::strings::GAME_OVER_TEXT, stats.to_vec(), false, &constants::stats::GAME_OVER_ITEMS);
+    }
+
+    pub fn open_lifetime(&mut self, stats: &Stats) {
+        self.show(constants::strings::STATS_TEXT, vec![*stats], true, &[constants::pause::BACK_LABEL]);
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale;
+        self.menu.set_scale(scale);
+    }
+
+    pub fn handle_input(&mut self, input: MenuInput) -> Option<StatsAction> {
+        let action = match self.menu.handle_input(input)? {
+            MenuEvent::Activated(0) if !self.lifetime => StatsAction::PlayAgain,
+            MenuEvent::Activated(_) | MenuEvent::Back => StatsAction::Close,
+            MenuEvent::Changed(_) => return None,
+        };
+
+        self.close();
+
+        Some(action)
+    }
+
+    pub fn render(&self, canvas: &mut Canvas<Window>, screen_bounds: Rect, locale: &Locale) -> Result<(), String> {
+        if !self.open {
+            return Ok(());
+        }
+
+        canvas.set_blend_mode(BlendMode::Blend);
+        canvas.set_draw_color(Color::RGBA(0, 0, 0, constants::pause::DIM_ALPHA));
+        canvas.fill_rect(screen_bounds)?;
+        canvas.set_blend_mode(BlendMode::None);
+
+        let center = screen_bounds.x() + screen_bounds.width() as i32 / 2;
+        let mut y = screen_bounds.y() + screen_bounds.height() as i32 / constants::stats::TOP_MARGIN_DIVISOR;
+
+        ui::render_text(locale.translate(self.title), center, y, Align::Center, self.scale, canvas)?;
+        y += ui::measure_text(self.title, self.scale).1 as i32 * 2;
+
+        let scale = self.scale * constants::stats::TABLE_SCALE;
+        let padding = (constants::font::MARGIN as f32 * scale) as i32;
+        let column_width = (constants::stats::COLUMN_WIDTH as f32 * scale) as i32;
+
+        if self.columns.len() > 1 {
+            self.columns.iter().enumerate().try_for_each(|(i, _)| {
+                let header = format!("{} {}", locale.translate(constants::strings::PLAYER_TEXT), i + 1);
+                ui::render_text(&header, center + padding + i as i32 * column_width, y, Align::Left, scale, canvas)
+            })?;
+            y += ui::measure_text("", scale).1 as i32 * 2;
+        }
+
+        let labels = constants::stats::LABELS
+            .iter()
+            .skip(!self.lifetime as usize)
+            .map(|l| locale.translate(l))
+            .collect::<Vec<&str>>()
+            .join("\n");
+        ui::render_text(&labels, center - padding, y, Align::Right, scale, canvas)?;
+
+        self.columns.iter().enumerate().try_for_each(|(i, stats)| {
+            let values = stats.get_lines(self.lifetime).join("\n");
+            ui::render_text(&values, center + padding + i as i32 * column_width, y, Align::Left, scale, canvas)
+        })?;
+
+        let menu_top = screen_bounds.y() + screen_bounds.height() as i32 * 3 / 4;
+        self.menu.render(
+            canvas,
+            Rect::new(screen_bounds.x(), menu_top, screen_bounds.width(), screen_bounds.height() / 4),
+            locale,
+        )
+    }
+
+    fn show(&mut self, title: &'static str, columns: Vec<Stats>, lifetime: bool, items: &[&str]) {
+        self.open = true;
+        self.title = title;
+        self.columns = columns;
+        self.lifetime = lifetime;
+        self.menu = Menu::new("", items.iter().map(|s| Widget::Button(s.to_string())).collect());
+        self.menu.set_scale(self.scale);
+    }
+}
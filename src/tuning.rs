@@ -0,0 +1,71 @@
+use crate::constants;
+
+#[derive(Clone, Copy)]
+pub struct Tuning {
+    pub turn_speed: f32,
+    pub acceleration: f32,
+    pub deceleration: f32,
+    pub black_hole_force: f32,
+    pub black_hole_range: f32,
+    pub max_aliens: u32,
+    pub max_black_holes: u32,
+    pub spawn_rate: f32,
+    pub autofire_rate: f32,
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Tuning {
+            turn_speed: constants::player::TURN_SPEED,
+            acceleration: constants::player::ACCELERATION,
+            deceleration: constants::player::DECELERATION,
+            black_hole_force: constants::black_hole::FORCE_FAC,
+            black_hole_range: constants::black_hole::RANGE_FAC,
+            max_aliens: constants::alien::MAX_ALIENS,
+            max_black_holes: constants::black_hole::MAX_BLACK_HOLES,
+            spawn_rate: 1.0,
+            autofire_rate: 0.0,
+        }
+    }
+}
+
+impl Tuning {
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let parse = |v: &str| {
+            v.parse::<f32>()
+                .ok()
+                .filter(|v| v.is_finite() && *v >= 0.0)
+                .ok_or(format!("{}{}", constants::strings::INVALID_VALUE_ERROR, v))
+        };
+
+        match key {
+            "turn_speed" => self.turn_speed = parse(value)?,
+            "acceleration" => self.acceleration = parse(value)?,
+            "deceleration" => self.deceleration = parse(value)?,
+            "black_hole_force" => self.black_hole_force = parse(value)?,
+            "black_hole_range" => self.black_hole_range = parse(value)?,
+            "max_aliens" => self.max_aliens = parse(value)? as u32,
+            "max_black_holes" => self.max_black_holes = parse(value)? as u32,
+            "spawn_rate" => self.spawn_rate = parse(value)?.max(constants::tuning::MIN_SPAWN_RATE),
+            "autofire_rate" => self.autofire_rate = parse(value)?,
+            _ => return Err(format!("{}{}", constants::strings::UNKNOWN_KEY_ERROR, key)),
+        }
+
+        Ok(())
+    }
+
+    pub fn get(&self, key: &str) -> Option<String> {
+        Some(match key {
+            "turn_speed" => self.turn_speed.to_string(),
+            "acceleration" => self.acceleration.to_string(),
+            "deceleration" => self.deceleration.to_string(),
+            "black_hole_force" => self.black_hole_force.to_string(),
+            "black_hole_range" => self.black_hole_range.to_string(),
+            "max_aliens" => self.max_aliens.to_string(),
+            "max_black_holes" => self.max_black_holes.to_string(),
+            "spawn_rate" => self.spawn_rate.to_string(),
+            "autofire_rate" => self.autofire_rate.to_string(),
+            _ => return None,
+        })
+    }
+}
@@ -0,0 +1,251 @@
+use crate::asteroid::Asteroid;
+use crate::black_hole::BlackHole;
+use crate::constants;
+use crate::event::GameEvent;
+use crate::locale::Locale;
+use crate::session::Session;
+use crate::ui::{self, Align};
+use sdl2::rect::Rect;
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+use std::f32::consts::PI;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Step {
+    Rotate,
+    Thrust,
+    Stop,
+    Shoot,
+    Wrap,
+    BlackHole,
+    Done,
+}
+
+impl Step {
+    pub const ALL: [Step; 7] = [
+        Step::Rotate,
+        Step::Thrust,
+        Step::Stop,
+        Step::Shoot,
+        Step::Wrap,
+        Step::BlackHole,
+        Step::Done,
+    ];
+
+    pub fn get_index(self) -> usize {
+        self as usize
+    }
+
+    pub fn get_prompt(self) -> &'static str {
+        constants::tutorial::PROMPTS[self.get_index()]
+    }
+}
+
+pub struct Tutorial {
+    step: Step,
+
+    rotation: f32,
+    braked: bool,
+    last: Option<(f32, f32, f32)>,
+    timer: f32,
+    complete: f32,
+    caught: f32,
+}
+
+impl Default for Tutorial {
+    fn default() -> Self {
+        Tutorial {
+            step: Step::Rotate,
+
+            rotation: 0.0,
+            braked: false,
+            last: None,
+            timer: 0.0,
+            complete: 0.0,
+            caught: 0.0,
+        }
+    }
+}
+
+impl Tutorial {
+    pub fn new() -> Self {
+        Tutorial::default()
+    }
+
+    pub fn get_step(&self) -> Step {
+        self.step
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.step == Step::Done && self.timer >= constants::tutorial::DONE_SECONDS
+    }
+
+    pub fn update(&mut self, session: &mut Session, screen_bounds: Rect, dt: f32) {
+        self.caught = (self.caught - dt).max(0.0);
+        self.timer += dt;
+
+        if self.complete > 0.0 {
+            self.complete -= dt;
+
+            if self.complete <= 0.0 {
+                self.next_step(session, screen_bounds);
+            }
+            return;
+        }
+
+        let hit = session
+            .get_events()
+            .iter()
+            .any(|e| matches!(e, GameEvent::PlayerHit { .. }));
+
+        let Some(player) = session.get_player(0) else {
+            return;
+        };
+
+        let (x, y, vx, vy) = player.get_pos_and_vel();
+        let angle = player.get_angle();
+        let speed = (vx * vx + vy * vy).sqrt();
+        let last = self.last.replace((x, y, angle));
+
+        let done = match self.step {
+            Step::Rotate => {
+                if let Some((_, _, last_angle)) = last {
+                    self.rotation += ((angle - last_angle + PI).rem_euclid(2.0 * PI) - PI).abs();
+                }
+                self.rotation >= constants::tutorial::ROTATION
+            }
+            Step::Thrust => speed >= constants::tutorial::THRUST_SPEED,
+            Step::Stop => {
+                self.braked |= player.is_thrusting() && angle.cos() * vx + angle.sin() * vy < 0.0;
+                self.braked && speed < constants::tutorial::STOP_SPEED
+            }
+            Step::Shoot => {
+                let shot = session
+                    .get_events()
+                    .iter()
+                    .any(|e| matches!(e, GameEvent::AsteroidDestroyed { by: Some(0), .. }));
+
+                if !shot && (hit || session.get_asteroids().is_empty()) {
+                    self.setup(session, screen_bounds);
+                }
+                shot
+            }
+            Step::Wrap => last.is_some_and(|(last_x, last_y, _)| {
+                !hit && ((x - last_x).abs() > screen_bounds.width() as f32 / 2.0
+                    || (y - last_y).abs() > screen_bounds.height() as f32 / 2.0)
+            }),
+            Step::BlackHole => {
+                let caught = session.get_black_holes().iter().any(|b| {
+                    (b.get_x() - x).powi(2) + (b.get_y() - y).powi(2) < b.get_radius().powi(2)
+                });
+
+                if caught {
+                    self.caught = constants::tutorial::CAUGHT_SECONDS;
+                    session.die(screen_bounds);
+                }
+                if caught || hit || session.get_black_holes().is_empty() {
+                    self.setup(session, screen_bounds);
+                }
+                self.timer >= constants::tutorial::AVOID_SECONDS
+            }
+            Step::Done => false,
+        };
+
+        if done {
+            self.complete = constants::tutorial::COMPLETE_DELAY;
+        }
+    }
+
+    fn next_step(&mut self, session: &mut Session, screen_bounds: Rect) {
+        self.step = Step::ALL[(self.step.get_index() + 1).min(Step::ALL.len() - 1)];
+
+        self.rotation = 0.0;
+        self.braked = false;
+        self.last = None;
+
+        self.setup(session, screen_bounds);
+    }
+
+    fn setup(&mut self, session: &mut Session, screen_bounds: Rect) {
+        self.timer = 0.0;
+        self.last = None;
+
+        let Some(player) = session.get_player(0) else {
+            return;
+        };
+
+        let (x, y, _, _) = player.get_pos_and_vel();
+        let angle = player.get_angle();
+        let width = screen_bounds.width() as f32;
+        let height = screen_bounds.height() as f32;
+        let mut rng = rand::rng();
+
+        match self.step {
+            Step::Shoot => {
+                let mut asteroid = Asteroid::new(
+                    (x + angle.cos() * constants::tutorial::ASTEROID_DISTANCE).rem_euclid(width),
+                    (y + angle.sin() * constants::tutorial::ASTEROID_DISTANCE).rem_euclid(height),
+                    constants::tutorial::ASTEROID_RADIUS,
+                    &mut rng,
+                );
+                asteroid.set_velocity(0.0, 0.0);
+                session.add_asteroid(asteroid);
+            }
+            Step::BlackHole => {
+                let mut black_hole = BlackHole::new(screen_bounds, session.get_time(), &mut rng);
+                black_hole.set_location(
+                    (x + constants::tutorial::BLACK_HOLE_DISTANCE).rem_euclid(width),
+                    y,
+                );
+                session.add_black_hole(black_hole);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn render(
+        &self,
+        canvas: &mut Canvas<Window>,
+        screen_bounds: Rect,
+        scale: f32,
+        locale: &Locale,
+    ) -> Result<(), String> {
+        let scale = scale * constants::tutorial::TEXT_SCALE;
+
+        let mut text = if self.step == Step::Done {
+            locale.translate(self.step.get_prompt()).to_string()
+        } else {
+            format!(
+                "{} {}/{}\n{}",
+                locale.translate(constants::tutorial::STEP_TEXT),
+                self.step.get_index() + 1,
+                Step::ALL.len() - 1,
+                locale.translate(self.step.get_prompt())
+            )
+        };
+
+        if self.complete > 0.0 {
+            text = format!("{} {}", text, constants::font::CHECK_ICON);
+        } else if self.step == Step::BlackHole {
+            let remaining = (constants::tutorial::AVOID_SECONDS - self.timer).max(0.0).ceil();
+            text = format!("{} {}", text, remaining);
+        }
+
+        if self.caught > 0.0 {
+            text = format!("{}\n{}", locale.translate(constants::tutorial::CAUGHT_TEXT), text);
+        }
+
+        let (_, height) = ui::measure_text(&text, scale);
+
+        ui::render_text(
+            &text,
+            screen_bounds.x() + screen_bounds.width() as i32 / 2,
+            screen_bounds.y() + screen_bounds.height() as i32
+                - constants::tutorial::BOTTOM_MARGIN
+                - height as i32,
+            Align::Center,
+            scale,
+            canvas,
+        )
+    }
+}
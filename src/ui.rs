@@ -0,0 +1,294 @@
+use crate::constants;
+use crate::font;
+use crate::input::MenuInput;
+use crate::locale::Locale;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+pub fn measure_text(text: &str, scale: f32) -> (u32, u32) {
+    let lines = text.lines().count().max(1) as f32;
+    let longest = text.lines().map(|l| l.chars().count()).max().unwrap_or(0) as f32;
+
+    let height = lines * (2 * constants::font::FONT_SIZE) as f32 + (lines - 1.0) * constants::font::LINE_SPACING as f32;
+
+    if longest == 0.0 {
+        return (0, (height * scale) as u32);
+    }
+
+    let width = longest * constants::font::FONT_SIZE as f32 + (longest - 1.0) * constants::font::MARGIN as f32;
+
+    ((width * scale) as u32, (height * scale) as u32)
+}
+
+pub fn render_text(
+    text: &str,
+    x: i32,
+    y: i32,
+    align: Align,
+    scale: f32,
+    canvas: &mut Canvas<Window>,
+) -> Result<(), String> {
+    render_text_colored(text, x, y, align, scale, Color::RGB(255, 255, 255), canvas)
+}
+
+pub fn render_text_colored(
+    text: &str,
+    x: i32,
+    y: i32,
+    align: Align,
+    scale: f32,
+    color: Color,
+    canvas: &mut Canvas<Window>,
+) -> Result<(), String> {
+    let line_height = ((2 * constants::font::FONT_SIZE + constants::font::LINE_SPACING) as f32 * scale) as i32;
+
+    text.lines().enumerate().try_for_each(|(row, line)| {
+        let (width, _) = measure_text(line, scale);
+
+        let x = match align {
+            Align::Left => x,
+            Align::Center => x - width as i32 / 2,
+            Align::Right => x - width as i32,
+        };
+
+        font::render_text_styled(line, x, y + row as i32 * line_height, scale, color, canvas)
+    })
+}
+
+pub enum Widget {
+    Button(String),
+    Toggle {
+        label: String,
+        value: bool,
+    },
+    Slider {
+        label: String,
+        value: f32,
+        min: f32,
+        max: f32,
+        step: f32,
+    },
+    Choice {
+        label: String,
+        options: Vec<String>,
+        selected: usize,
+    },
+}
+
+impl Widget {
+    fn get_text(&self, locale: &Locale) -> String {
+        match self {
+            Widget::Button(label) => locale.translate(label).to_string(),
+            Widget::Toggle { label, value } => format!(
+                "{} {}",
+                locale.translate(label),
+                locale.translate(if *value {
+                    constants::strings::ON_TEXT
+                } else {
+                    constants::strings::OFF_TEXT
+                })
+            ),
+            Widget::Slider { label, .. } => locale.translate(label).to_string(),
+            Widget::Choice { label, options, selected } => format!(
+                "{} < {} >",
+                locale.translate(label),
+                options.get(*selected).map_or("", |o| locale.translate(o))
+            ),
+        }
+    }
+
+    fn adjust(&mut self, direction: i32) -> bool {
+        match self {
+            Widget::Button(_) => false,
+            Widget::Toggle { value, .. } => {
+                *value = !*value;
+                true
+            }
+            Widget::Slider {
+                value, min, max, step, ..
+            } => {
+                let old = *value;
+                *value = (((*value / *step).round() + direction as f32) * *step).clamp(*min, *max);
+                *value != old
+            }
+            Widget::Choice { options, selected, .. } => {
+                let count = options.len() as i32;
+                if count == 0 {
+                    return false;
+                }
+
+                *selected = (*selected as i32 + direction).rem_euclid(count) as usize;
+                true
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum MenuEvent {
+    Activated(usize),
+    Changed(usize),
+    Back,
+}
+
+pub struct Menu {
+    title: String,
+    items: Vec<Widget>,
+    focus: usize,
+    scale: f32,
+}
+
+impl Menu {
+    pub fn new(title: &str, items: Vec<Widget>) -> Self {
+        Menu {
+            title: String::from(title),
+            items,
+            focus: 0,
+            scale: 1.0,
+        }
+    }
+
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale;
+    }
+
+    pub fn set_title(&mut self, title: &str) {
+        self.title = String::from(title);
+    }
+
+    pub fn get_item_count(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn get_focus(&self) -> usize {
+        self.focus
+    }
+
+    pub fn set_focus(&mut self, focus: usize) {
+        self.focus = focus.min(self.items.len().saturating_sub(1));
+    }
+
+    pub fn get_toggle(&self, index: usize) -> bool {
+        matches!(self.items.get(index), Some(Widget::Toggle { value: true, .. }))
+    }
+
+    pub fn set_toggle(&mut self, index: usize, new_value: bool) {
+        if let Some(Widget::Toggle { value, .. }) = self.items.get_mut(index) {
+            *value = new_value;
+        }
+    }
+
+    pub fn get_slider(&self, index: usize) -> f32 {
+        match self.items.get(index) {
+            Some(Widget::Slider { value, .. }) => *value,
+            _ => 0.0,
+        }
+    }
+
+    pub fn set_slider(&mut self, index: usize, new_value: f32) {
+        if let Some(Widget::Slider { value, min, max, .. }) = self.items.get_mut(index) {
+            *value = new_value.clamp(*min, *max);
+        }
+    }
+
+    pub fn get_choice(&self, index: usize) -> usize {
+        match self.items.get(index) {
+            Some(Widget::Choice { selected, .. }) => *selected,
+            _ => 0,
+        }
+    }
+
+    pub fn set_choice(&mut self, index: usize, new_selected: usize) {
+        if let Some(Widget::Choice { options, selected, .. }) = self.items.get_mut(index) {
+            *selected = new_selected.min(options.len().saturating_sub(1));
+        }
+    }
+
+    pub fn handle_input(&mut self, input: MenuInput) -> Option<MenuEvent> {
+        let count = self.items.len();
+        if count == 0 {
+            return (input == MenuInput::Back).then_some(MenuEvent::Back);
+        }
+
+        let item = &mut self.items[self.focus];
+
+        match input {
+            MenuInput::Up => self.focus = (self.focus + count - 1) % count,
+            MenuInput::Down => self.focus = (self.focus + 1) % count,
+            MenuInput::Left if item.adjust(-1) => return Some(MenuEvent::Changed(self.focus)),
+            MenuInput::Right if item.adjust(1) => return Some(MenuEvent::Changed(self.focus)),
+            MenuInput::Select => {
+                return match item {
+                    Widget::Button(_) | Widget::Slider { .. } => Some(MenuEvent::Activated(self.focus)),
+                    _ => item.adjust(1).then_some(MenuEvent::Changed(self.focus)),
+                };
+            }
+            MenuInput::Back => return Some(MenuEvent::Back),
+            _ => {}
+        }
+
+        None
+    }
+
+    pub fn render(&self, canvas: &mut Canvas<Window>, screen_bounds: Rect, locale: &Locale) -> Result<(), String> {
+        let title = locale.translate(&self.title);
+        let (_, text_height) = measure_text(title, self.scale);
+        let line_height = text_height + (constants::ui::ITEM_SPACING as f32 * self.scale) as u32;
+        let padding = (constants::font::MARGIN as f32 * self.scale) as i32;
+
+        let center = screen_bounds.x() + screen_bounds.width() as i32 / 2;
+        let top = screen_bounds.y() + screen_bounds.height() as i32 / 2
+            - ((self.items.len() as u32 + 2) * line_height) as i32 / 2;
+
+        render_text(title, center, top, Align::Center, self.scale, canvas)?;
+
+        self.items.iter().enumerate().try_for_each(|(i, item)| {
+            let text = item.get_text(locale);
+            let (mut width, _) = measure_text(&text, self.scale);
+
+            if let Widget::Slider { .. } = item {
+                width += padding as u32 + (constants::ui::SLIDER_WIDTH as f32 * self.scale) as u32;
+            }
+
+            let x = center - width as i32 / 2;
+            let y = top + ((i as u32 + 2) * line_height) as i32;
+
+            font::render_text_styled(&text, x, y, self.scale, Color::RGB(255, 255, 255), canvas)?;
+
+            if let Widget::Slider { value, min, max, .. } = item {
+                let (text_width, _) = measure_text(&text, self.scale);
+                let bar_width = (constants::ui::SLIDER_WIDTH as f32 * self.scale) as u32;
+                let fraction = if max > min { (value - min) / (max - min) } else { 0.0 };
+
+                let bar = Rect::new(x + text_width as i32 + padding, y, bar_width, text_height);
+                canvas.draw_rect(bar)?;
+                canvas.fill_rect(Rect::new(
+                    bar.x(),
+                    bar.y(),
+                    ((bar_width as f32 * fraction) as u32).max(1),
+                    text_height,
+                ))?;
+            }
+
+            if i == self.focus {
+                canvas.draw_rect(Rect::new(
+                    x - padding,
+                    y - padding,
+                    width + 2 * padding as u32,
+                    text_height + 2 * padding as u32,
+                ))?;
+            }
+
+            Ok::<(), String>(())
+        })
+    }
+}